@@ -0,0 +1,29 @@
+//! Parsing of the recorded entries with the owned and the borrowed models
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use oxdi::models::{borrowed, RetrieveEntry};
+use std::hint::black_box;
+
+const CRAB: &str = include_str!("../tests/fixtures/api%2Fv2%2Fentries%2Fen-gb%2Fcrab.json");
+
+fn models(c: &mut Criterion) {
+    let fixture: serde_json::Value = serde_json::from_str(CRAB).unwrap();
+    let json = serde_json::to_vec(&fixture["response"]["body"]).unwrap();
+
+    let mut group = c.benchmark_group("entries");
+    group.throughput(Throughput::Bytes(json.len() as u64));
+    group.bench_function("owned", |b| {
+        b.iter(|| serde_json::from_slice::<RetrieveEntry>(black_box(&json)).unwrap())
+    });
+    group.bench_function("borrowed", |b| {
+        b.iter(|| borrowed::RetrieveEntry::from_slice(black_box(&json)).unwrap())
+    });
+    group.bench_function("borrowed_into_owned", |b| {
+        b.iter(|| {
+            RetrieveEntry::from(borrowed::RetrieveEntry::from_slice(black_box(&json)).unwrap())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, models);
+criterion_main!(benches);
@@ -0,0 +1,89 @@
+use super::EntriesQuery;
+use crate::models::Lemmatron;
+use crate::{error, trace};
+use crate::{Client, Endpoint, Error, Result};
+
+// "https://od-api.oxforddictionaries.com/api/v2/lemmas/<language_code>/<word_id>"
+impl Client {
+    /// Request the headwords (lemmas) of the inflected word
+    /// (e.g. crabs -> crab).
+    pub async fn lemmas<'s, D>(&'s self, word_id: &'s str, query: &'s EntriesQuery) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        use std::io::Write;
+        trace!("request to lemmas api");
+        if !self.has_dictionary() {
+            error!("lang {} doesn't support Lemmas API", self.source_language);
+            return Err(Error::UnsupportedApi {
+                lang: self.source_language,
+                api: "lemmas".to_owned(),
+            });
+        }
+
+        query.validate(Endpoint::Lemmas)?;
+        let endpoint = Endpoint::Lemmas.as_str();
+        let mut path = Vec::with_capacity(
+            endpoint.len() + self.source_language.code().len() + word_id.len() + 64,
+        );
+        write!(&mut path, "{}/{}/", endpoint, self.source_language.code()).unwrap();
+        super::write_word_id(&mut path, word_id, Endpoint::Lemmas)?;
+        super::write_query(&mut path, query);
+        self.get_json(std::str::from_utf8(&path).unwrap()).await
+    }
+
+    /// Same as [`entries`](Client::entries), but when the `word_id` is
+    /// not a headword (the API returns `404`), looks up its lemmas and
+    /// retries with each of them until one succeeds. If none of the
+    /// lemmas is found, the `404` error of the `word_id` is returned.
+    ///
+    /// The returned [`Lemmatized::lemma`](Lemmatized::lemma) is `None`
+    /// when `word_id` was found directly.
+    pub async fn entries_lemmatized<'s, D>(
+        &'s self,
+        word_id: &'s str,
+        query: &'s EntriesQuery,
+    ) -> Result<Lemmatized<D>>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        let not_found = match self.entries(word_id, query).await {
+            Ok(value) => return Ok(Lemmatized { lemma: None, value }),
+            Err(err) if err.is_not_found() => err,
+            Err(err) => return Err(err),
+        };
+        trace!(word_id, "headword not found, falling back to lemmas");
+        let lemmatron: Lemmatron = match self.lemmas(word_id, &EntriesQuery::new()).await {
+            Ok(lemmatron) => lemmatron,
+            Err(err) if err.is_not_found() => return Err(not_found),
+            Err(err) => return Err(err),
+        };
+
+        let word_id = super::normalize_word_id(word_id);
+        for lemma in lemmatron.lemmas() {
+            if super::normalize_word_id(lemma) == word_id {
+                continue;
+            }
+            trace!(lemma, "trying lemma");
+            match self.entries(lemma, query).await {
+                Ok(value) => {
+                    return Ok(Lemmatized {
+                        lemma: Some(lemma.to_owned()),
+                        value,
+                    })
+                }
+                Err(err) if err.is_not_found() => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Err(not_found)
+    }
+}
+
+/// Response of [`entries_lemmatized`](Client::entries_lemmatized)
+#[derive(Debug)]
+pub struct Lemmatized<D> {
+    /// Lemma which was used instead of the requested word
+    pub lemma: Option<String>,
+    pub value: D,
+}
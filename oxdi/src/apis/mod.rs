@@ -1 +1,82 @@
 pub mod entries;
+pub mod inflections;
+pub mod lemmas;
+mod query;
+pub mod search;
+pub mod sentences;
+pub mod thesaurus;
+pub mod translations;
+pub mod utility;
+pub mod words;
+
+pub use query::{EntriesQuery, Field, SearchQuery};
+
+/// Normalizes the word or phrase the way the API ids are: words of
+/// a phrase are lowercased and separated by underscores, a single word
+/// keeps its case, so that [`strict_match`](EntriesQuery::strict_match)
+/// can tell e.g. `Polish` from `polish`
+/// ## Example
+/**```
+use oxdi::apis::normalize_word_id;
+assert_eq!(normalize_word_id(" Ice  Cream"), "ice_cream");
+assert_eq!(normalize_word_id("o'clock"), "o'clock");
+assert_eq!(normalize_word_id(" Polish "), "Polish");
+```*/
+pub fn normalize_word_id(word: &str) -> String {
+    let mut words = word.split_whitespace();
+    let first = words.next().unwrap_or_default();
+    let mut words = words.peekable();
+    if words.peek().is_none() {
+        return first.to_owned();
+    }
+    let mut id = String::with_capacity(word.len());
+    for (i, word) in std::iter::once(first).chain(words).enumerate() {
+        if i != 0 {
+            id.push('_');
+        }
+        id.extend(word.chars().flat_map(char::to_lowercase));
+    }
+    id
+}
+
+/// Appends normalized and percent-encoded `word_id` to the `path`,
+/// so that it stays a single path segment.
+///
+/// Empty ids and ids of only dots (`.`, `..`) would change the path
+/// and fail with [`Error::InvalidQuery`](crate::Error::InvalidQuery)
+pub(crate) fn write_word_id(
+    path: &mut Vec<u8>,
+    word_id: &str,
+    endpoint: crate::Endpoint,
+) -> crate::Result<()> {
+    let word_id = normalize_word_id(word_id);
+    if word_id.bytes().all(|byte| byte == b'.') {
+        return Err(crate::Error::InvalidQuery {
+            api: endpoint.as_str().to_owned(),
+            param: "word_id".to_owned(),
+        });
+    }
+    write_encoded(path, &word_id);
+    Ok(())
+}
+
+/// Appends percent-encoded `value`, only the unreserved characters are kept
+pub(crate) fn write_encoded(path: &mut Vec<u8>, value: &str) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            path.push(byte);
+        } else {
+            path.extend_from_slice(&[
+                b'%',
+                HEX[usize::from(byte >> 4)],
+                HEX[usize::from(byte & 0xF)],
+            ]);
+        }
+    }
+}
+
+/// Appends `?` and the params of the `query` to the `path`
+pub(crate) fn write_query(path: &mut Vec<u8>, query: &EntriesQuery) {
+    query.write_params(path, b'?');
+}
@@ -0,0 +1,365 @@
+use crate::models::ids::{LexicalCategoryId, RegionId, RegisterId};
+use crate::{Endpoint, Error, Result};
+use std::fmt;
+
+/// Filters and fields of the dictionary APIs, shared by
+/// [`entries`](crate::Client::entries), [`translations`](crate::Client::translations),
+/// [`words`](crate::Client::words), [`lemmas`](crate::Client::lemmas),
+/// [`inflections`](crate::Client::inflections), [`sentences`](crate::Client::sentences)
+/// and [`thesaurus`](crate::Client::thesaurus).
+///
+/// Every param is sent at most once, repeated filters are merged
+/// and comma separated ids are split.
+/// Params not supported by the API fail the request with
+/// [`Error::InvalidQuery`](crate::Error::InvalidQuery).
+/// ## Example
+/**```
+use oxdi::apis::{EntriesQuery, Field};
+use oxdi::models::LexicalCategoryId;
+let query = EntriesQuery::new()
+    .fields(&[Field::Definitions, Field::Examples])
+    .lexical_category(LexicalCategoryId::Noun)
+    .lexical_category("verb")
+    .strict_match(false);
+assert_eq!(
+    query.to_string(),
+    "fields=definitions%2Cexamples&lexicalCategory=noun%2Cverb&strictMatch=false"
+);
+```*/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EntriesQuery {
+    fields: Vec<Field>,
+    grammatical_features: Vec<String>,
+    lexical_categories: Vec<String>,
+    domains: Vec<String>,
+    registers: Vec<String>,
+    strict_match: Option<bool>,
+}
+
+/// Parts of the response to include
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field {
+    Definitions,
+    Domains,
+    Etymologies,
+    Examples,
+    Pronunciations,
+    Regions,
+    Registers,
+    /// Only for the [`translations`](crate::Client::translations) API
+    Translations,
+    VariantForms,
+    /// Only for the [`thesaurus`](crate::Client::thesaurus) API
+    Synonyms,
+    /// Only for the [`thesaurus`](crate::Client::thesaurus) API
+    Antonyms,
+}
+
+impl Field {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Field::Definitions => "definitions",
+            Field::Domains => "domains",
+            Field::Etymologies => "etymologies",
+            Field::Examples => "examples",
+            Field::Regions => "regions",
+            Field::Registers => "registers",
+            Field::Translations => "translations",
+            Field::VariantForms => "variantForms",
+            Field::Pronunciations => "pronunciations",
+            Field::Synonyms => "synonyms",
+            Field::Antonyms => "antonyms",
+        }
+    }
+
+    /// Checks whether the field is known to the `endpoint`
+    const fn is_supported_by(&self, endpoint: Endpoint) -> bool {
+        match (endpoint, self) {
+            (Endpoint::Thesaurus, Field::Synonyms | Field::Antonyms) => true,
+            (Endpoint::Thesaurus, _) | (_, Field::Synonyms | Field::Antonyms) => false,
+            (Endpoint::Translations, _) => true,
+            (Endpoint::Entries | Endpoint::Words, Field::Translations) => false,
+            (Endpoint::Entries | Endpoint::Words, _) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Names of the params in the query string
+const FIELDS: &str = "fields";
+const GRAMMATICAL_FEATURES: &str = "grammaticalFeatures";
+const LEXICAL_CATEGORY: &str = "lexicalCategory";
+const DOMAINS: &str = "domains";
+const REGISTERS: &str = "registers";
+const STRICT_MATCH: &str = "strictMatch";
+
+impl EntriesQuery {
+    /// Empty query, the API defaults are used
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include the `field` in the response
+    pub fn field(mut self, field: Field) -> Self {
+        if !self.fields.contains(&field) {
+            self.fields.push(field);
+        }
+        self
+    }
+
+    /// Include the `fields` in the response
+    pub fn fields(self, fields: &[Field]) -> Self {
+        fields.iter().fold(self, |query, &field| query.field(field))
+    }
+
+    /// Only entries with the grammatical feature, e.g. `plural`
+    pub fn grammatical_feature(mut self, id: &str) -> Self {
+        merge(&mut self.grammatical_features, id);
+        self
+    }
+
+    /// Only entries of the lexical category, e.g. [`Noun`](LexicalCategoryId::Noun)
+    pub fn lexical_category(mut self, id: impl Into<LexicalCategoryId>) -> Self {
+        merge(&mut self.lexical_categories, id.into().as_str());
+        self
+    }
+
+    /// Only entries of the domain, e.g. `art`
+    pub fn domain(mut self, id: &str) -> Self {
+        merge(&mut self.domains, id);
+        self
+    }
+
+    /// Only entries of the register, e.g. [`Informal`](RegisterId::Informal)
+    pub fn register(mut self, id: impl Into<RegisterId>) -> Self {
+        merge(&mut self.registers, id.into().as_str());
+        self
+    }
+
+    /// Whether diacritics and case must match the `word_id` exactly
+    pub fn strict_match(mut self, strict_match: bool) -> Self {
+        self.strict_match = Some(strict_match);
+        self
+    }
+
+    /// Checks that the `endpoint` supports every param of the query
+    pub(crate) fn validate(&self, endpoint: Endpoint) -> Result<()> {
+        // grammatical features and lexical category, domains and registers, strictMatch
+        let (lexical, labels, strict_match) = match endpoint {
+            Endpoint::Entries | Endpoint::Translations => (true, true, true),
+            Endpoint::Words => (true, true, false),
+            Endpoint::Lemmas => (true, false, false),
+            Endpoint::Inflections => (true, false, true),
+            Endpoint::Sentences | Endpoint::Thesaurus => (false, false, true),
+            _ => (false, false, false),
+        };
+        let unsupported = if let Some(field) = self
+            .fields
+            .iter()
+            .find(|field| !field.is_supported_by(endpoint))
+        {
+            format!("{}={}", FIELDS, field.as_str())
+        } else if !lexical && !self.grammatical_features.is_empty() {
+            GRAMMATICAL_FEATURES.to_owned()
+        } else if !lexical && !self.lexical_categories.is_empty() {
+            LEXICAL_CATEGORY.to_owned()
+        } else if !labels && !self.domains.is_empty() {
+            DOMAINS.to_owned()
+        } else if !labels && !self.registers.is_empty() {
+            REGISTERS.to_owned()
+        } else if !strict_match && self.strict_match.is_some() {
+            STRICT_MATCH.to_owned()
+        } else {
+            return Ok(());
+        };
+        Err(Error::InvalidQuery {
+            api: endpoint.as_str().to_owned(),
+            param: unsupported,
+        })
+    }
+
+    /// Appends the params to the `path`, the first one
+    /// is preceded by the `separator`
+    pub(crate) fn write_params(&self, path: &mut Vec<u8>, mut separator: u8) {
+        let separator = &mut separator;
+        write_param(
+            path,
+            separator,
+            FIELDS,
+            self.fields.iter().map(Field::as_str),
+        );
+        write_param(
+            path,
+            separator,
+            GRAMMATICAL_FEATURES,
+            self.grammatical_features.iter().map(String::as_str),
+        );
+        write_param(
+            path,
+            separator,
+            LEXICAL_CATEGORY,
+            self.lexical_categories.iter().map(String::as_str),
+        );
+        write_param(
+            path,
+            separator,
+            DOMAINS,
+            self.domains.iter().map(String::as_str),
+        );
+        write_param(
+            path,
+            separator,
+            REGISTERS,
+            self.registers.iter().map(String::as_str),
+        );
+        write_param(
+            path,
+            separator,
+            STRICT_MATCH,
+            self.strict_match.map(bool_str),
+        );
+    }
+}
+
+/// Query string without the leading `?`
+impl fmt::Display for EntriesQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut query = Vec::new();
+        self.write_params(&mut query, b'?');
+        // params are percent-encoded, so always ASCII
+        f.write_str(std::str::from_utf8(query.get(1..).unwrap_or_default()).unwrap())
+    }
+}
+
+/// Params of the [`search`](crate::Client::search),
+/// [`search_translations`](crate::Client::search_translations) and
+/// [`search_thesaurus`](crate::Client::search_thesaurus) APIs.
+///
+/// Every param is sent at most once, the last value wins.
+/// ## Example
+/**```
+use oxdi::apis::SearchQuery;
+use oxdi::models::RegionId;
+let query = SearchQuery::new()
+    .prefix(true)
+    .region(RegionId::British)
+    .region("us")
+    .limit(20)
+    .limit(10);
+assert_eq!(query.to_string(), "prefix=true&regions=british%2Cus&limit=10");
+```*/
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    prefix: Option<bool>,
+    regions: Vec<RegionId>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+const PREFIX: &str = "prefix";
+const REGIONS: &str = "regions";
+const LIMIT: &str = "limit";
+const OFFSET: &str = "offset";
+
+impl SearchQuery {
+    /// Empty query, the API defaults are used
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match only the words starting with the query
+    pub fn prefix(mut self, prefix: bool) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Only words of the region, e.g. [`British`](RegionId::British)
+    pub fn region(mut self, id: impl Into<RegionId>) -> Self {
+        let id = id.into();
+        if !self.regions.contains(&id) {
+            self.regions.push(id);
+        }
+        self
+    }
+
+    /// Maximum number of results, the API default is 5000
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Number of results to skip
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Appends the params to the `path`, the first one
+    /// is preceded by the `separator`
+    pub(crate) fn write_params(&self, path: &mut Vec<u8>, mut separator: u8) {
+        let separator = &mut separator;
+        write_param(path, separator, PREFIX, self.prefix.map(bool_str));
+        write_param(
+            path,
+            separator,
+            REGIONS,
+            self.regions.iter().map(RegionId::as_str),
+        );
+        let limit = self.limit.map(|limit| limit.to_string());
+        write_param(path, separator, LIMIT, limit.as_deref());
+        let offset = self.offset.map(|offset| offset.to_string());
+        write_param(path, separator, OFFSET, offset.as_deref());
+    }
+}
+
+/// Query string without the leading `?`
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut query = Vec::new();
+        self.write_params(&mut query, b'?');
+        // params are percent-encoded, so always ASCII
+        f.write_str(std::str::from_utf8(query.get(1..).unwrap_or_default()).unwrap())
+    }
+}
+
+/// Appends `name=values` with the comma separated `values` to the `path`,
+/// nothing if there are no values. The `separator` precedes the param
+/// and becomes `&` after it
+fn write_param<'v>(
+    path: &mut Vec<u8>,
+    separator: &mut u8,
+    name: &str,
+    values: impl IntoIterator<Item = &'v str>,
+) {
+    let mut values = values.into_iter().peekable();
+    if values.peek().is_none() {
+        return;
+    }
+    path.push(*separator);
+    path.extend_from_slice(name.as_bytes());
+    path.push(b'=');
+    for (i, value) in values.enumerate() {
+        if i != 0 {
+            path.extend_from_slice(b"%2C");
+        }
+        super::write_encoded(path, value);
+    }
+    *separator = b'&';
+}
+
+const fn bool_str(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+/// Adds the comma separated `ids` to the `values` skipping the known ones
+fn merge(values: &mut Vec<String>, ids: &str) {
+    for id in ids.split(',').map(str::trim).filter(|id| !id.is_empty()) {
+        if !values.iter().any(|value| value == id) {
+            values.push(id.to_owned());
+        }
+    }
+}
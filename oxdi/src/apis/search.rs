@@ -0,0 +1,154 @@
+use super::SearchQuery;
+use crate::models::SearchResults;
+use crate::{error, trace};
+use crate::{Client, Endpoint, Error, Language, Result};
+use futures::stream::{self, Stream};
+
+// "https://od-api.oxforddictionaries.com/api/v2/search/<language_code>?q=<query>"
+// "https://od-api.oxforddictionaries.com/api/v2/search/translations/<source_lang>/<target_lang>?q=<query>"
+// "https://od-api.oxforddictionaries.com/api/v2/search/thesaurus/<language_code>?q=<query>"
+impl Client {
+    /// Search the dictionary for headwords matching the `query`
+    pub async fn search<'s, D>(&'s self, query: &'s str, params: &'s SearchQuery) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        self.search_in(Scope::Dictionary, query, params).await
+    }
+
+    /// Search for headwords in the source language which have
+    /// translations to the `target` language
+    pub async fn search_translations<'s, D>(
+        &'s self,
+        query: &'s str,
+        target: Language,
+        params: &'s SearchQuery,
+    ) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        self.search_in(Scope::Translations(target), query, params)
+            .await
+    }
+
+    /// Search for headwords which have thesaurus entries
+    pub async fn search_thesaurus<'s, D>(
+        &'s self,
+        query: &'s str,
+        params: &'s SearchQuery,
+    ) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        self.search_in(Scope::Thesaurus, query, params).await
+    }
+
+    /// Stream of search result pages of `page_size` items,
+    /// ends after the first short (or failed) page. The limit and
+    /// offset of the `params` are replaced for every page
+    pub fn search_pages<'s>(
+        &'s self,
+        scope: Scope,
+        query: &'s str,
+        page_size: u32,
+        params: &'s SearchQuery,
+    ) -> impl Stream<Item = Result<SearchResults>> + 's {
+        stream::unfold(Some(0u32), move |offset| async move {
+            let offset = offset?;
+            let page_params = params.clone().limit(page_size).offset(offset);
+            trace!(offset, page_size, "requesting search page");
+
+            let page: SearchResults = match self.search_in(scope, query, &page_params).await {
+                Ok(page) => page,
+                Err(err) => return Some((Err(err), None)),
+            };
+            let len = page.results.as_ref().map_or(0, |results| results.len()) as u32;
+            let next = if len < page_size || page_size == 0 {
+                None
+            } else {
+                Some(offset + len)
+            };
+            if len == 0 && offset != 0 {
+                return None;
+            }
+            Some((Ok(page), next))
+        })
+    }
+
+    async fn search_in<D>(&self, scope: Scope, query: &str, params: &SearchQuery) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        use std::io::Write;
+        trace!(?scope, "request to search api");
+        let supported = match scope {
+            Scope::Dictionary => self.has_dictionary(),
+            Scope::Translations(_) => self.has_translations(),
+            Scope::Thesaurus => self.has_thesaurus(),
+        };
+        if !supported {
+            error!(
+                "lang {} doesn't support {} API",
+                self.source_language,
+                scope.api()
+            );
+            return Err(Error::UnsupportedApi {
+                lang: self.source_language,
+                api: scope.api().to_owned(),
+            });
+        }
+
+        let endpoint = Endpoint::Search.as_str();
+        let mut path = Vec::with_capacity(endpoint.len() + query.len() + 64);
+        let source = self.source_language.code();
+        match scope {
+            Scope::Dictionary => write!(&mut path, "{}/{}", endpoint, source).unwrap(),
+            Scope::Translations(target) => {
+                if !self.translates_to(target) {
+                    error!(
+                        "translation from {} to {} is not supported",
+                        self.source_language, target
+                    );
+                    return Err(Error::UnsupportedLanguagePair {
+                        source_language: self.source_language,
+                        target_language: target,
+                    });
+                }
+                write!(
+                    &mut path,
+                    "{}/translations/{}/{}",
+                    endpoint,
+                    source,
+                    target.code()
+                )
+                .unwrap();
+            }
+            Scope::Thesaurus => write!(&mut path, "{}/thesaurus/{}", endpoint, source).unwrap(),
+        }
+        write!(&mut path, "?q=").unwrap();
+        super::write_encoded(&mut path, query);
+        params.write_params(&mut path, b'&');
+        self.get_json(std::str::from_utf8(&path).unwrap()).await
+    }
+}
+
+/// Which search API to query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// Headwords of the dictionary
+    Dictionary,
+    /// Headwords which have translations to the language
+    Translations(Language),
+    /// Headwords which have thesaurus entries
+    Thesaurus,
+}
+
+impl Scope {
+    const fn api(&self) -> &'static str {
+        match self {
+            Scope::Dictionary => "search",
+            Scope::Translations(_) => "search translations",
+            Scope::Thesaurus => "search thesaurus",
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use super::EntriesQuery;
+use crate::{error, trace};
+use crate::{Client, Endpoint, Error, Result};
+
+// "https://od-api.oxforddictionaries.com/api/v2/sentences/<language_code>/<word_id>"
+impl Client {
+    /// Request example sentences from the corpus
+    /// for the word.
+    /// ## Arguments
+    /// `word_id` - should be dictionary headword (e.g. crab, not crabs)
+    pub async fn sentences<'s, D>(&'s self, word_id: &'s str, query: &'s EntriesQuery) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        use std::io::Write;
+        trace!("request to sentences api");
+        if !self.has_sentences() {
+            error!(
+                "lang {} doesn't support Sentences API",
+                self.source_language
+            );
+            return Err(Error::UnsupportedApi {
+                lang: self.source_language,
+                api: "sentences".to_owned(),
+            });
+        }
+
+        query.validate(Endpoint::Sentences)?;
+        let endpoint = Endpoint::Sentences.as_str();
+        let mut path = Vec::with_capacity(
+            endpoint.len() + self.source_language.code().len() + word_id.len() + 64,
+        );
+        write!(&mut path, "{}/{}/", endpoint, self.source_language.code()).unwrap();
+        super::write_word_id(&mut path, word_id, Endpoint::Sentences)?;
+        super::write_query(&mut path, query);
+        self.get_json(std::str::from_utf8(&path).unwrap()).await
+    }
+}
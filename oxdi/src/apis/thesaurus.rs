@@ -0,0 +1,37 @@
+use super::EntriesQuery;
+use crate::{error, trace};
+use crate::{Client, Endpoint, Error, Result};
+
+// "https://od-api.oxforddictionaries.com/api/v2/thesaurus/<language_code>/<word_id>"
+impl Client {
+    /// Request synonyms and antonyms of the word.
+    /// ## Arguments
+    /// `word_id` - should be dictionary headword (e.g. crab, not crabs)
+    pub async fn thesaurus<'s, D>(&'s self, word_id: &'s str, query: &'s EntriesQuery) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        use std::io::Write;
+        trace!("request to thesaurus api");
+        if !self.has_thesaurus() {
+            error!(
+                "lang {} doesn't support Thesaurus API",
+                self.source_language
+            );
+            return Err(Error::UnsupportedApi {
+                lang: self.source_language,
+                api: "thesaurus".to_owned(),
+            });
+        }
+
+        query.validate(Endpoint::Thesaurus)?;
+        let endpoint = Endpoint::Thesaurus.as_str();
+        let mut path = Vec::with_capacity(
+            endpoint.len() + self.source_language.code().len() + word_id.len() + 64,
+        );
+        write!(&mut path, "{}/{}/", endpoint, self.source_language.code()).unwrap();
+        super::write_word_id(&mut path, word_id, Endpoint::Thesaurus)?;
+        super::write_query(&mut path, query);
+        self.get_json(std::str::from_utf8(&path).unwrap()).await
+    }
+}
@@ -0,0 +1,67 @@
+use super::EntriesQuery;
+use crate::{error, trace};
+use crate::{Client, Endpoint, Error, Language, Result};
+
+// "https://od-api.oxforddictionaries.com/api/v2/translations/<source_lang>/<target_lang>/<word_id>"
+impl Client {
+    /// Request translations of the word from the source language
+    /// to the `target` language.
+    /// ## Arguments
+    /// `word_id` - should be dictionary headword (e.g. crab, not crabs)
+    ///
+    /// `target` - must be one of the
+    /// [`target_languages`](Language::target_languages) of the source language
+    pub async fn translations<'s, D>(
+        &'s self,
+        word_id: &'s str,
+        target: Language,
+        query: &'s EntriesQuery,
+    ) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        use std::io::Write;
+        trace!("request to translations api");
+        if !self.has_translations() {
+            error!(
+                "lang {} doesn't support Translations API",
+                self.source_language
+            );
+            return Err(Error::UnsupportedApi {
+                lang: self.source_language,
+                api: "translations".to_owned(),
+            });
+        }
+        if !self.translates_to(target) {
+            error!(
+                "translation from {} to {} is not supported",
+                self.source_language, target
+            );
+            return Err(Error::UnsupportedLanguagePair {
+                source_language: self.source_language,
+                target_language: target,
+            });
+        }
+
+        query.validate(Endpoint::Translations)?;
+        let endpoint = Endpoint::Translations.as_str();
+        let mut path = Vec::with_capacity(
+            endpoint.len()
+                + self.source_language.code().len()
+                + target.code().len()
+                + word_id.len()
+                + 64,
+        );
+        write!(
+            &mut path,
+            "{}/{}/{}/",
+            endpoint,
+            self.source_language.code(),
+            target.code(),
+        )
+        .unwrap();
+        super::write_word_id(&mut path, word_id, Endpoint::Translations)?;
+        super::write_query(&mut path, query);
+        self.get_json(std::str::from_utf8(&path).unwrap()).await
+    }
+}
@@ -0,0 +1,88 @@
+use crate::models::{EndpointNames, Labels, RetrieveLanguages};
+use crate::trace;
+use crate::{Client, Endpoint, Language, Result};
+
+// "https://od-api.oxforddictionaries.com/api/v2/<endpoint>/<language_code>"
+// "https://od-api.oxforddictionaries.com/api/v2/<endpoint>/<source_lang>/<target_lang>"
+impl Client {
+    /// Query all available grammatical features of
+    /// the language
+    pub async fn grammatical_features(&self) -> Result<Labels> {
+        self.labels(Endpoint::GrammaticalFeatures, None).await
+    }
+
+    /// Query all available domains of the language, or of the
+    /// bilingual dataset if `target` is specified.
+    ///
+    /// The ids are valid values of the `Domains` params
+    pub async fn domains(&self, target: Option<Language>) -> Result<Labels> {
+        self.labels(Endpoint::Domains, target).await
+    }
+
+    /// Query all available lexical categories of the language.
+    ///
+    /// The ids are valid values of the `LexicalCategory` params
+    pub async fn lexical_categories(&self) -> Result<Labels> {
+        self.labels(Endpoint::LexicalCategories, None).await
+    }
+
+    /// Query all available regions of the language
+    pub async fn regions(&self) -> Result<Labels> {
+        self.labels(Endpoint::Regions, None).await
+    }
+
+    /// Query all available registers of the language, or of the
+    /// bilingual dataset if `target` is specified.
+    ///
+    /// The ids are valid values of the `Registers` params
+    pub async fn registers(&self, target: Option<Language>) -> Result<Labels> {
+        self.labels(Endpoint::Registers, target).await
+    }
+
+    /// Query the fields available for every endpoint, or only for
+    /// the `endpoint` (e.g. `entries`)
+    pub async fn fields(&self, endpoint: Option<&str>) -> Result<EndpointNames> {
+        self.endpoint_names(Endpoint::Fields, endpoint).await
+    }
+
+    /// Query the filters available for every endpoint, or only for
+    /// the `endpoint` (e.g. `entries`)
+    pub async fn filters(&self, endpoint: Option<&str>) -> Result<EndpointNames> {
+        self.endpoint_names(Endpoint::Filters, endpoint).await
+    }
+
+    /// Query all monolingual and bilingual datasets
+    /// available in the API
+    pub async fn languages(&self) -> Result<RetrieveLanguages> {
+        let endpoint = Endpoint::Languages;
+        trace!(?endpoint, "requesting utility API");
+        self.get_json(endpoint.as_str()).await
+    }
+
+    async fn labels(&self, endpoint: Endpoint, target: Option<Language>) -> Result<Labels> {
+        let path = match target {
+            Some(target) => format!(
+                "{}/{}/{}",
+                endpoint.as_str(),
+                self.source_language.code(),
+                target.code()
+            ),
+            None => format!("{}/{}", endpoint.as_str(), self.source_language.code()),
+        };
+        trace!(?endpoint, ?path, "requesting utility API");
+        self.get_json(&path).await
+    }
+
+    async fn endpoint_names(
+        &self,
+        endpoint: Endpoint,
+        of_endpoint: Option<&str>,
+    ) -> Result<EndpointNames> {
+        let path = match of_endpoint {
+            Some(of_endpoint) => format!("{}/{}", endpoint.as_str(), of_endpoint),
+            None => endpoint.as_str().to_owned(),
+        };
+        trace!(?endpoint, ?path, "requesting utility API");
+        self.get_json(&path).await
+    }
+}
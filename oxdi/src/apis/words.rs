@@ -0,0 +1,37 @@
+use super::EntriesQuery;
+use crate::{error, trace};
+use crate::{Client, Endpoint, Error, Result};
+
+// "https://od-api.oxforddictionaries.com/api/v2/words/<language_code>?q=<word>"
+impl Client {
+    /// Request entries of the word, unlike [`entries`](Client::entries)
+    /// the `word` may be an inflected form (e.g. crabs).
+    ///
+    /// The response has the same shape as for `entries`, so it
+    /// can be deserialized into [`RetrieveEntry`](crate::models::RetrieveEntry).
+    pub async fn words<'s, D>(&'s self, word: &'s str, query: &'s EntriesQuery) -> Result<D>
+    where
+        D: serde::de::DeserializeOwned,
+    {
+        use std::io::Write;
+        trace!("request to words api");
+        if !self.has_dictionary() {
+            error!("lang {} doesn't support Words API", self.source_language);
+            return Err(Error::UnsupportedApi {
+                lang: self.source_language,
+                api: "words".to_owned(),
+            });
+        }
+
+        query.validate(Endpoint::Words)?;
+        let endpoint = Endpoint::Words.as_str();
+        let mut path = Vec::with_capacity(
+            endpoint.len() + self.source_language.code().len() + word.len() + 64,
+        );
+        write!(&mut path, "{}/{}", endpoint, self.source_language.code()).unwrap();
+        write!(&mut path, "?q=").unwrap();
+        super::write_encoded(&mut path, word);
+        query.write_params(&mut path, b'&');
+        self.get_json(std::str::from_utf8(&path).unwrap()).await
+    }
+}
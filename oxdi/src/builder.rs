@@ -0,0 +1,206 @@
+use crate::fixtures::{FixtureClient, FixtureMode};
+use crate::{
+    CachePolicy, Capabilities, Client, DiskCache, Error, Language, RateLimiter, ResponseCache,
+    Result, RetryPolicy, API_BASE_URL,
+};
+use http_client::isahc::IsahcClient;
+use isahc::config::Configurable;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use surf::Url;
+
+/// Builder of the [`Client`](Client)
+/// ## Example
+/**```no_run
+use oxdi::{ClientBuilder, Language};
+use std::time::Duration;
+let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+    .base_url("http://localhost:8080/api/v2/")
+    .timeout(Duration::from_secs(10))
+    .user_agent("my-app/1.0")
+    .build()
+    .expect("handle errors");
+```*/
+#[derive(Debug)]
+pub struct ClientBuilder {
+    app_id: String,
+    app_key: String,
+    source_language: Language,
+    base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    headers: Vec<(String, String)>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
+    disk_cache: Option<DiskCache>,
+    offline: bool,
+    strict: bool,
+    fixtures: Option<(PathBuf, FixtureMode)>,
+    http_client: Option<surf::Client>,
+}
+
+impl ClientBuilder {
+    /// Creates new builder with the default settings
+    pub fn new(app_id: String, app_key: String, source_language: Language) -> Self {
+        Self {
+            app_id,
+            app_key,
+            source_language,
+            base_url: API_BASE_URL.to_owned(),
+            timeout: None,
+            connect_timeout: None,
+            headers: Vec::new(),
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            cache: None,
+            disk_cache: None,
+            offline: false,
+            strict: false,
+            fixtures: None,
+            http_client: None,
+        }
+    }
+
+    /// Base URL of the API, by default [`API_BASE_URL`](API_BASE_URL).
+    ///
+    /// Useful to point the client at a mock server or a proxy
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_owned();
+        self
+    }
+
+    /// Timeout of the whole request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout of establishing the connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Header sent with every request, can be called multiple times
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Value of the `User-Agent` header
+    pub fn user_agent(self, user_agent: &str) -> Self {
+        self.header("User-Agent", user_agent)
+    }
+
+    /// Retry failed requests according to the `policy`,
+    /// by default requests are not retried
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Delay requests according to the `rate_limiter`, every
+    /// attempt (including retries) is counted
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Serve repeated requests from the `cache`
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Persist the responses in the `disk_cache`, it is consulted
+    /// after the in-memory [`cache`](ClientBuilder::cache)
+    pub fn disk_cache(mut self, disk_cache: DiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
+    /// Never send requests, serve responses only from the caches.
+    ///
+    /// Requests which are not cached fail with
+    /// [`Error::OfflineCacheMiss`](Error::OfflineCacheMiss)
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Fail the requests whose responses have fields unknown to the
+    /// [`models`](crate::models) or fields of unexpected types.
+    ///
+    /// The error is [`Error::SchemaMismatch`](Error::SchemaMismatch) with
+    /// the JSON path of the field, by default unknown fields are kept
+    /// in the `extra` maps of the models
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Record the responses to or replay them from the fixture files
+    /// in the `dir`, see [`FixtureClient`](FixtureClient)
+    pub fn fixtures(mut self, dir: impl Into<PathBuf>, mode: FixtureMode) -> Self {
+        self.fixtures = Some((dir.into(), mode));
+        self
+    }
+
+    /// Use the `http_client` to send requests, the timeouts
+    /// and the fixtures are ignored in that case
+    pub fn http_client(mut self, http_client: surf::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    pub fn build(self) -> Result<Client> {
+        let mut base_url = self.base_url;
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+        let base_url = Url::parse(&base_url).map_err(Error::InvalidBaseUrl)?;
+
+        let http_client = match (self.http_client, self.fixtures) {
+            (Some(http_client), _) => http_client,
+            (None, Some((dir, FixtureMode::Replay))) => {
+                surf::Client::with_http_client(FixtureClient::replay(dir))
+            }
+            (None, fixtures) => {
+                let mut builder = isahc::HttpClient::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                let isahc_client = builder.build().map_err(surf::Error::from)?;
+                let isahc_client = IsahcClient::from_client(isahc_client);
+                match fixtures {
+                    Some((dir, _)) => surf::Client::with_http_client(
+                        FixtureClient::record(dir, isahc_client).map_err(surf::Error::from)?,
+                    ),
+                    None => surf::Client::with_http_client(isahc_client),
+                }
+            }
+        };
+
+        Ok(Client {
+            app_id: self.app_id,
+            app_key: self.app_key,
+            source_language: self.source_language,
+            http_client,
+            base_url,
+            headers: self.headers,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+            cache: self.cache,
+            cache_policy: CachePolicy::default(),
+            disk_cache: self.disk_cache,
+            offline: self.offline,
+            strict: self.strict,
+            capabilities: Arc::new(RwLock::new(Capabilities::fallback())),
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// In-memory cache of the raw API responses with LRU eviction.
+///
+/// Responses are keyed by the request path relative to the base URL,
+/// which consists of the endpoint, language, word and serialized params,
+/// e.g. `entries/en-gb/ice_cream?fields=definitions%2Cexamples`.
+/// Only successful responses are stored. Clones share the same storage.
+/// ## Example
+/**```no_run
+use oxdi::{ClientBuilder, Language, ResponseCache};
+use std::time::Duration;
+let cache = ResponseCache::new(1000).ttl(Duration::from_secs(60 * 60));
+let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+    .cache(cache.clone())
+    .build()
+    .expect("handle errors");
+println!("{:?}", cache.stats());
+```*/
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    inner: Arc<Mutex<Inner>>,
+}
+
+/// How a request uses the [`ResponseCache`](ResponseCache),
+/// see [`Client::with_cache_policy`](crate::Client::with_cache_policy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CachePolicy {
+    /// Serve from the cache if possible, store the response otherwise
+    #[default]
+    Use,
+    /// Neither read nor store the response
+    Bypass,
+    /// Always request the API and replace the cached response
+    Refresh,
+}
+
+/// Counters of the [`ResponseCache`](ResponseCache)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Number of the cached responses
+    pub len: usize,
+}
+
+#[derive(Debug)]
+struct Inner {
+    capacity: usize,
+    ttl: Option<Duration>,
+    entries: HashMap<String, Entry>,
+    /// Keys ordered from the least recently used
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    body: Arc<str>,
+    inserted_at: Instant,
+    used_at: u64,
+}
+
+impl ResponseCache {
+    /// Creates cache of at most `capacity` responses without TTL
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                capacity,
+                ttl: None,
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+                hits: 0,
+                misses: 0,
+            })),
+        }
+    }
+
+    /// Responses older than `ttl` are not served
+    pub fn ttl(self, ttl: Duration) -> Self {
+        self.inner.lock().unwrap().ttl = Some(ttl);
+        self
+    }
+
+    /// Cached response of the request `path`, counts a hit or a miss
+    pub fn get(&self, path: &str) -> Option<Arc<str>> {
+        let mut inner = self.inner.lock().unwrap();
+        let expired = match (inner.entries.get(path), inner.ttl) {
+            (None, _) => {
+                inner.misses += 1;
+                return None;
+            }
+            (Some(entry), Some(ttl)) => entry.inserted_at.elapsed() > ttl,
+            (Some(_), None) => false,
+        };
+        if expired {
+            inner.remove(path);
+            inner.misses += 1;
+            return None;
+        }
+        inner.hits += 1;
+        inner.tick += 1;
+        let tick = inner.tick;
+        let Inner {
+            entries, recency, ..
+        } = &mut *inner;
+        let entry = entries.get_mut(path).unwrap();
+        let key = recency.remove(&entry.used_at).unwrap();
+        recency.insert(tick, key);
+        entry.used_at = tick;
+        Some(entry.body.clone())
+    }
+
+    /// Stores the response of the request `path`, evicts
+    /// the least recently used one if the cache is full
+    pub fn insert(&self, path: &str, body: impl Into<Arc<str>>) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.capacity == 0 {
+            return;
+        }
+        inner.remove(path);
+        while inner.entries.len() >= inner.capacity {
+            let lru = match inner.recency.keys().next() {
+                Some(&tick) => inner.recency.remove(&tick).unwrap(),
+                None => break,
+            };
+            inner.entries.remove(&lru);
+        }
+        inner.tick += 1;
+        let tick = inner.tick;
+        inner.recency.insert(tick, path.to_owned());
+        inner.entries.insert(
+            path.to_owned(),
+            Entry {
+                body: body.into(),
+                inserted_at: Instant::now(),
+                used_at: tick,
+            },
+        );
+    }
+
+    /// Removes the response of the request `path`, see
+    /// [`Client::invalidate_entries`](crate::Client::invalidate_entries)
+    /// to remove an entries response without building its path
+    pub fn invalidate(&self, path: &str) {
+        self.inner.lock().unwrap().remove(path);
+    }
+
+    /// Removes all responses, counters are kept
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.clear();
+        inner.recency.clear();
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        let inner = self.inner.lock().unwrap();
+        CacheStats {
+            hits: inner.hits,
+            misses: inner.misses,
+            len: inner.entries.len(),
+        }
+    }
+}
+
+impl Inner {
+    fn remove(&mut self, path: &str) {
+        if let Some(entry) = self.entries.remove(path) {
+            self.recency.remove(&entry.used_at);
+        }
+    }
+}
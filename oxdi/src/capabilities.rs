@@ -0,0 +1,139 @@
+use crate::models::utility::{LanguageDataset, LanguageLabel};
+use crate::Language;
+use std::collections::HashSet;
+
+/// Which dictionaries and translations are available in the API.
+///
+/// By default built from the hard-coded tables of [`Language`](Language),
+/// can be replaced with the live data of the `/languages` endpoint,
+/// see [`Client::refresh_capabilities`](crate::Client::refresh_capabilities).
+/// The thesaurus and sentences APIs are not described by `/languages`,
+/// so [`has_thesaurus`](Capabilities::has_thesaurus) and
+/// [`has_sentences`](Capabilities::has_sentences) always answer from
+/// the hard-coded tables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    dictionaries: HashSet<Language>,
+    translations: HashSet<(Language, Language)>,
+    live: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::fallback()
+    }
+}
+
+impl Capabilities {
+    /// Capabilities from the hard-coded tables
+    pub fn fallback() -> Self {
+        let dictionaries = Language::ALL
+            .iter()
+            .copied()
+            .filter(Language::entries_api)
+            .collect();
+        let translations = Language::ALL
+            .iter()
+            .flat_map(|&source| {
+                source
+                    .target_languages()
+                    .unwrap_or(&[])
+                    .iter()
+                    .map(move |&target| (source, target))
+            })
+            .collect();
+        Self {
+            dictionaries,
+            translations,
+            live: false,
+        }
+    }
+
+    /// Capabilities from the datasets returned by the `/languages` endpoint,
+    /// datasets of unknown languages are skipped. Falls back to the
+    /// hard-coded tables if none of the datasets is known
+    pub fn from_datasets(datasets: &[LanguageDataset]) -> Self {
+        let mut dictionaries = HashSet::new();
+        let mut translations = HashSet::new();
+        for dataset in datasets {
+            let sources = languages_of(&dataset.source_language, dataset.region.as_deref());
+            match dataset.target_language.as_ref() {
+                Some(target) => {
+                    let targets = languages_of(target, dataset.region.as_deref());
+                    for &source in &sources {
+                        for &target in &targets {
+                            translations.insert((source, target));
+                        }
+                    }
+                }
+                None => dictionaries.extend(sources),
+            }
+        }
+        if dictionaries.is_empty() && translations.is_empty() {
+            return Self::fallback();
+        }
+        Self {
+            dictionaries,
+            translations,
+            live: true,
+        }
+    }
+
+    /// Whether the capabilities were loaded from the API
+    pub fn is_live(&self) -> bool {
+        self.live
+    }
+
+    /// Checks whether there is a monolingual dictionary of the `language`,
+    /// i.e. entries, lemmas, inflections, words and search APIs are available
+    pub fn has_dictionary(&self, language: Language) -> bool {
+        self.dictionaries.contains(&language)
+    }
+
+    /// Checks whether the thesaurus of the `language` is available,
+    /// the same for the live and fallback capabilities
+    pub fn has_thesaurus(&self, language: Language) -> bool {
+        language.thesaurus_api()
+    }
+
+    /// Checks whether the example sentences of the `language` are available,
+    /// the same for the live and fallback capabilities
+    pub fn has_sentences(&self, language: Language) -> bool {
+        language.sentences_api()
+    }
+
+    /// Checks whether the `source` can be translated to any language
+    pub fn has_translations(&self, source: Language) -> bool {
+        self.translations.iter().any(|&(from, _)| from == source)
+    }
+
+    /// Checks whether translations from `source` to `target` are available
+    pub fn translates(&self, source: Language, target: Language) -> bool {
+        self.translations.contains(&(source, target))
+    }
+
+    /// All languages the `source` can be translated to
+    pub fn target_languages(&self, source: Language) -> Vec<Language> {
+        Language::ALL
+            .iter()
+            .copied()
+            .filter(|&target| self.translates(source, target))
+            .collect()
+    }
+}
+
+/// Maps the language label of the dataset to the `Language`s,
+/// English datasets without a region are both British and American
+fn languages_of(label: &LanguageLabel, region: Option<&str>) -> Vec<Language> {
+    let id = label.id.to_lowercase();
+    if let Some(region) = region {
+        if let Some(language) = Language::from_str(&format!("{}-{}", id, region.to_lowercase())) {
+            return vec![language];
+        }
+    }
+    match Language::from_str(&id) {
+        Some(language) => vec![language],
+        None if id == "en" => vec![Language::EnGb, Language::EnUs],
+        None => Vec::new(),
+    }
+}
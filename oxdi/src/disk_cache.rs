@@ -0,0 +1,106 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Longest file name, most file systems allow 255 bytes
+const MAX_FILE_NAME_LEN: usize = 200;
+
+/// Persistent cache of the raw API responses, a directory
+/// with a JSON file per request path.
+///
+/// Unlike [`ResponseCache`](crate::ResponseCache) the responses never
+/// expire, which allows to use the client [offline](crate::ClientBuilder::offline).
+/// Files are read and written synchronously.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Opens the cache in the `dir`, creates the directory if needed
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cached response of the request `path`
+    pub fn get(&self, path: &str) -> Option<String> {
+        match fs::read_to_string(self.file_of(path)) {
+            Ok(body) => Some(body),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+            Err(err) => {
+                warn!(%err, path, "failed to read cached response");
+                None
+            }
+        }
+    }
+
+    /// Stores the response of the request `path`
+    pub fn insert(&self, path: &str, body: &str) -> io::Result<()> {
+        // write to a temporary file first, so that readers never see a partial response
+        let file = self.file_of(path);
+        let tmp = file.with_extension("json.tmp");
+        fs::write(&tmp, body)?;
+        fs::rename(&tmp, &file)
+    }
+
+    /// Removes the response of the request `path`
+    pub fn invalidate(&self, path: &str) -> io::Result<()> {
+        match fs::remove_file(self.file_of(path)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes all responses
+    pub fn clear(&self) -> io::Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let file = entry?.path();
+            if file.extension().is_some_and(|ext| ext == "json") {
+                fs::remove_file(file)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn file_of(&self, path: &str) -> PathBuf {
+        self.dir.join(file_name(path))
+    }
+}
+
+/// Percent-encoded `key` with the `.json` extension, too long names
+/// are shortened and suffixed with the hash of the `key`.
+///
+/// Uppercase letters are encoded too, so that keys differing only in case
+/// don't collide on case-insensitive file systems
+pub(crate) fn file_name(key: &str) -> String {
+    let mut name = String::with_capacity(key.len() + 5);
+    for byte in key.bytes() {
+        if byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-' || byte == b'_' {
+            name.push(byte as char);
+        } else {
+            write!(name, "%{:02X}", byte).unwrap();
+        }
+    }
+    if name.len() > MAX_FILE_NAME_LEN {
+        name.truncate(MAX_FILE_NAME_LEN - 17);
+        write!(name, "~{:016x}", fnv1a(key.as_bytes())).unwrap();
+    }
+    name.push_str(".json");
+    name
+}
+
+/// Stable hash of the bytes, unlike `DefaultHasher`
+/// does not change between Rust releases
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
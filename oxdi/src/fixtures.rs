@@ -0,0 +1,188 @@
+use crate::disk_cache::file_name;
+use http_client::http_types::{Error as HttpError, StatusCode, Url};
+use http_client::{async_trait, Config, HttpClient, Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::trace;
+
+/// Whether the [`FixtureClient`](FixtureClient) stores or serves the responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureMode {
+    /// Send requests and store the responses to the fixture files
+    Record,
+    /// Serve the responses from the fixture files without network
+    Replay,
+}
+
+/// HTTP transport which records the responses to fixture files
+/// and replays them, so tests are deterministic and don't need
+/// credentials.
+///
+/// Every request is stored in a separate JSON file named after its
+/// URL path and query, e.g. `api/v2/entries/en-gb/crab?fields=examples`.
+/// Request headers (and so the credentials) are never recorded.
+///
+/// Recorded bodies are stored as strings, so they are replayed byte for
+/// byte. Handwritten fixtures may have any other JSON value as the body,
+/// it is served serialized.
+/// ## Example
+/**```no_run
+use oxdi::{fixtures::FixtureMode, ClientBuilder, Language};
+let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+    .fixtures("tests/fixtures", FixtureMode::Replay)
+    .build()
+    .expect("handle errors");
+```*/
+#[derive(Debug)]
+pub struct FixtureClient {
+    dir: PathBuf,
+    /// `None` in the replay mode
+    inner: Option<Box<dyn HttpClient>>,
+    config: Config,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    /// Raw body as a string, any other JSON value is served serialized
+    body: Value,
+}
+
+impl FixtureClient {
+    /// Sends requests with the `inner` client and stores the responses in the `dir`
+    pub fn record(dir: impl Into<PathBuf>, inner: impl HttpClient) -> std::io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            inner: Some(Box::new(inner)),
+            config: Config::default(),
+        })
+    }
+
+    /// Serves the responses stored in the `dir`, requests without
+    /// a fixture fail
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            inner: None,
+            config: Config::default(),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn mode(&self) -> FixtureMode {
+        match self.inner {
+            Some(_) => FixtureMode::Record,
+            None => FixtureMode::Replay,
+        }
+    }
+
+    async fn record_response(
+        &self,
+        inner: &dyn HttpClient,
+        key: String,
+        req: Request,
+    ) -> Result<Response, HttpError> {
+        let method = req.method().to_string();
+        let mut resp = inner.send(req).await?;
+        let body = resp.body_string().await?;
+        let headers = resp
+            .iter()
+            .map(|(name, values)| (name.to_string(), values.last().to_string()))
+            .collect();
+        let fixture = Fixture {
+            request: RecordedRequest {
+                method,
+                url: key.clone(),
+            },
+            response: RecordedResponse {
+                status: resp.status().into(),
+                headers,
+                body: Value::String(body.clone()),
+            },
+        };
+        let file = self.dir.join(file_name(&key));
+        trace!(file = %file.display(), "recording fixture");
+        fs::write(&file, serde_json::to_vec_pretty(&fixture)?)?;
+        resp.set_body(body);
+        Ok(resp)
+    }
+
+    fn replay_response(&self, key: &str) -> Result<Response, HttpError> {
+        let file = self.dir.join(file_name(key));
+        trace!(file = %file.display(), "replaying fixture");
+        let fixture: Fixture = match fs::read(&file) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(HttpError::from_str(
+                    StatusCode::NotFound,
+                    format!("no fixture for `{}` in {}", key, self.dir.display()),
+                ))
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let mut resp = Response::new(StatusCode::try_from(fixture.response.status)?);
+        for (name, value) in &fixture.response.headers {
+            resp.insert_header(name.as_str(), value.as_str());
+        }
+        match fixture.response.body {
+            Value::String(body) => resp.set_body(body),
+            body => resp.set_body(body.to_string()),
+        }
+        Ok(resp)
+    }
+}
+
+/// Path and query of the `url` without the leading `/`
+fn fixture_key(url: &Url) -> String {
+    let path = url.path().trim_start_matches('/');
+    match url.query() {
+        Some(query) => format!("{}?{}", path, query),
+        None => path.to_owned(),
+    }
+}
+
+#[async_trait]
+impl HttpClient for FixtureClient {
+    async fn send(&self, req: Request) -> Result<Response, HttpError> {
+        let key = fixture_key(req.url());
+        match &self.inner {
+            Some(inner) => self.record_response(inner.as_ref(), key, req).await,
+            None => self.replay_response(&key),
+        }
+    }
+
+    fn set_config(&mut self, config: Config) -> http_client::http_types::Result<()> {
+        if let Some(inner) = &mut self.inner {
+            inner.set_config(config.clone())?;
+        }
+        self.config = config;
+        Ok(())
+    }
+
+    fn config(&self) -> &Config {
+        &self.config
+    }
+}
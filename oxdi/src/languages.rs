@@ -1,6 +1,6 @@
 use std::fmt;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     /// British English
     EnGb,
@@ -194,6 +194,47 @@ macro_rules! lang {
 }
 
 impl Language {
+    /// All supported languages
+    pub const ALL: &'static [Language] = &[
+        Language::EnGb,
+        Language::EnUs,
+        Language::Ar,
+        Language::Zh,
+        Language::Fa,
+        Language::Fr,
+        Language::Ka,
+        Language::De,
+        Language::El,
+        Language::Gu,
+        Language::Ha,
+        Language::Hi,
+        Language::Ig,
+        Language::Id,
+        Language::Xh,
+        Language::Zu,
+        Language::It,
+        Language::Lv,
+        Language::Ms,
+        Language::Mr,
+        Language::Nso,
+        Language::Pt,
+        Language::Qu,
+        Language::Ro,
+        Language::Ru,
+        Language::Tn,
+        Language::Es,
+        Language::Sw,
+        Language::Tg,
+        Language::Ta,
+        Language::Tt,
+        Language::Te,
+        Language::Tpi,
+        Language::Tk,
+        Language::Ur,
+        Language::Yo,
+    ];
+
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(string: &str) -> Option<Language> {
         match string {
             "en-gb" => Some(Language::EnGb),
@@ -277,6 +318,8 @@ impl Language {
         }
     }
 
+    /// Hard-coded, see [`Capabilities`](crate::Capabilities)
+    /// for the data of the API
     pub const fn target_languages(&self) -> Option<&'static [Language]> {
         let en_to_x = &[
             lang!(ar),
@@ -337,47 +380,56 @@ impl Language {
         }
     }
 
+    /// Hard-coded, see [`Capabilities`](crate::Capabilities)
+    /// for the data of the API
     pub const fn entries_api(&self) -> bool {
-        match self {
-            Language::EnGb => true,
-            Language::EnUs => true,
-            Language::Fr => true,
-            Language::Gu => true,
-            Language::Hi => true,
-            Language::Lv => true,
-            Language::Ro => true,
-            Language::Es => true,
-            Language::Sw => true,
-            Language::Ta => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Language::EnGb
+                | Language::EnUs
+                | Language::Fr
+                | Language::Gu
+                | Language::Hi
+                | Language::Lv
+                | Language::Ro
+                | Language::Es
+                | Language::Sw
+                | Language::Ta
+        )
     }
 
-    // pub const fn words_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn inflections_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn lemmas_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn search_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn search_translations_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn search_thesaurus_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn translations_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn thesaurus_api(&self) -> bool {
-    //     todo!()
-    // }
-    // pub const fn sentences_api(&self) -> bool {
-    //     todo!()
-    // }
+    /// Checks whether translations from `self` to `target` are available
+    pub fn translates_to(&self, target: Language) -> bool {
+        self.target_languages()
+            .is_some_and(|targets| targets.contains(&target))
+    }
+
+    pub const fn words_api(&self) -> bool {
+        self.entries_api()
+    }
+    pub const fn inflections_api(&self) -> bool {
+        self.entries_api()
+    }
+    /// Lemmas are available for every language with a dictionary
+    pub const fn lemmas_api(&self) -> bool {
+        self.entries_api()
+    }
+    pub const fn search_api(&self) -> bool {
+        self.entries_api()
+    }
+    pub const fn search_translations_api(&self) -> bool {
+        self.translations_api()
+    }
+    pub const fn search_thesaurus_api(&self) -> bool {
+        self.thesaurus_api()
+    }
+    pub const fn translations_api(&self) -> bool {
+        self.target_languages().is_some()
+    }
+    pub const fn thesaurus_api(&self) -> bool {
+        matches!(self, Language::EnGb | Language::EnUs)
+    }
+    pub const fn sentences_api(&self) -> bool {
+        matches!(self, Language::EnGb | Language::EnUs | Language::Es)
+    }
 }
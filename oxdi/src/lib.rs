@@ -1,14 +1,30 @@
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
 use surf::Url;
-use tracing::{error, trace};
+use tracing::{error, trace, warn};
 
 /// Version of the Oxford API with which the crate was tested
 pub static API_VERSION: &str = "2.5.0";
 pub mod apis;
+pub mod builder;
+pub mod cache;
+pub mod capabilities;
+pub mod disk_cache;
+pub mod fixtures;
 pub mod languages;
 pub mod models;
+pub mod rate_limit;
+pub mod retry;
+pub use apis::{EntriesQuery, SearchQuery};
+pub use builder::ClientBuilder;
+pub use cache::{CachePolicy, ResponseCache};
+pub use capabilities::Capabilities;
+pub use disk_cache::DiskCache;
 pub use languages::Language;
+pub use rate_limit::RateLimiter;
+pub use retry::RetryPolicy;
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -25,17 +41,118 @@ impl fmt::Display for ErrorExplanation {
     }
 }
 
+/// Details of the error response of the API
+#[derive(Debug)]
+pub struct ApiErrorDetails {
+    /// Requested path relative to the base URL
+    pub path: String,
+    pub x_request_id: Option<String>,
+    /// Absent when the body is not a JSON error
+    pub explanation: Option<ErrorExplanation>,
+}
+
+impl fmt::Display for ApiErrorDetails {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "request `{}`", self.path)?;
+        if let Some(x_request_id) = self.x_request_id.as_ref() {
+            write!(f, " with id `{}`", x_request_id)?;
+        }
+        if let Some(explanation) = self.explanation.as_ref() {
+            write!(f, ": {}", explanation)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("http error: {0}")]
     SurfError(surf::Error),
-    #[error("api request with id `{x_request_id}` returned error: {explanation}")]
-    ApiReturnedError {
-        explanation: ErrorExplanation,
-        x_request_id: String,
+    #[error("bad request, {0}")]
+    BadRequest(ApiErrorDetails),
+    #[error("unauthorized, check app id and key, {0}")]
+    Unauthorized(ApiErrorDetails),
+    #[error("forbidden, {0}")]
+    Forbidden(ApiErrorDetails),
+    #[error("not found, {0}")]
+    NotFound(ApiErrorDetails),
+    #[error("uri is too long, {0}")]
+    TooLong(ApiErrorDetails),
+    #[error("rate limited (retry after {retry_after:?}), {details}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        details: ApiErrorDetails,
+    },
+    #[error("server error {status}, {details}")]
+    ServerError {
+        status: surf::StatusCode,
+        details: ApiErrorDetails,
+    },
+    #[error("unexpected status {status}, {details}")]
+    HttpStatus {
+        status: surf::StatusCode,
+        details: ApiErrorDetails,
+    },
+    #[error("failed to decode response of `{path}` at `{json_path}`: {source}, body: `{body}`")]
+    Decode {
+        path: String,
+        /// Path of the failed field, e.g. `results[0].id`
+        json_path: String,
+        /// Beginning of the response body
+        body: String,
+        source: serde_json::Error,
     },
     #[error("language `{lang}` does not support `{api}`")]
     UnsupportedApi { lang: Language, api: String },
+    #[error("invalid base url: {0}")]
+    InvalidBaseUrl(surf::http::url::ParseError),
+    #[error("translation from `{source_language}` to `{target_language}` is not supported")]
+    UnsupportedLanguagePair {
+        source_language: Language,
+        target_language: Language,
+    },
+    #[error("`{param}` is not supported by the {api} API")]
+    InvalidQuery { api: String, param: String },
+    #[error("`{path}` is not cached and the client is offline")]
+    OfflineCacheMiss { path: String },
+    #[error("response of `{path}` does not match the models at `{json_path}`: {source}")]
+    SchemaMismatch {
+        path: String,
+        /// Path of the diverged field, e.g. `results[0].lexicalEntries`
+        json_path: String,
+        source: serde_json::Error,
+    },
+}
+
+impl Error {
+    /// Checks whether the API responded with `404 Not Found`
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Error::NotFound(_))
+    }
+
+    /// Checks whether repeating the request may succeed
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::SurfError(_) | Error::RateLimited { .. } => true,
+            Error::ServerError { status, .. } => retry::is_retryable_status(*status),
+            _ => false,
+        }
+    }
+
+    /// Details of the error response, if the API returned one
+    pub fn details(&self) -> Option<&ApiErrorDetails> {
+        match self {
+            Error::BadRequest(details)
+            | Error::Unauthorized(details)
+            | Error::Forbidden(details)
+            | Error::NotFound(details)
+            | Error::TooLong(details)
+            | Error::RateLimited { details, .. }
+            | Error::ServerError { details, .. }
+            | Error::HttpStatus { details, .. } => Some(details),
+            _ => None,
+        }
+    }
 }
 
 impl From<surf::Error> for Error {
@@ -44,70 +161,70 @@ impl From<surf::Error> for Error {
     }
 }
 
-const API_BASE_URL: &str = "https://od-api.oxforddictionaries.com/api/v2/";
+/// Default base URL of the API
+pub const API_BASE_URL: &str = "https://od-api.oxforddictionaries.com/api/v2/";
 #[derive(Debug, Clone, Copy)]
 /// All available endpoints
 enum Endpoint {
     Entries,
-    // Lemmas,
-    // Search,
-    // Translations,
-    // Thesaurus,
-    // Sentences,
-    // Utility,
-    // Words,
-    // Inflections,
+    Lemmas,
+    Search,
+    Translations,
+    Thesaurus,
+    Sentences,
+    Words,
+    Inflections,
+    // Utility
+    Domains,
+    Fields,
+    Filters,
     GrammaticalFeatures,
+    Languages,
+    LexicalCategories,
+    Regions,
+    Registers,
 }
 
 impl Endpoint {
     const fn as_str(&self) -> &'static str {
         match self {
             Endpoint::Entries => "entries",
-            // Endpoint::Lemmas => "lemmas",
-            // Endpoint::Search => "search",
-            // Endpoint::Translations => "translations",
-            // Endpoint::Thesaurus => "thesaurus",
-            // Endpoint::Sentences => "sentences",
-            // Endpoint::Utility => "utility",
-            // Endpoint::Words => "words",
-            // Endpoint::Inflections => "inflections",
+            Endpoint::Lemmas => "lemmas",
+            Endpoint::Search => "search",
+            Endpoint::Translations => "translations",
+            Endpoint::Thesaurus => "thesaurus",
+            Endpoint::Sentences => "sentences",
+            Endpoint::Words => "words",
+            Endpoint::Inflections => "inflections",
+            Endpoint::Domains => "domains",
+            Endpoint::Fields => "fields",
+            Endpoint::Filters => "filters",
             Endpoint::GrammaticalFeatures => "grammaticalFeatures",
+            Endpoint::Languages => "languages",
+            Endpoint::LexicalCategories => "lexicalcategories",
+            Endpoint::Regions => "regions",
+            Endpoint::Registers => "registers",
         }
     }
 }
 
-// impl<'eq> EntriesQuery<'eq> {
-//     const fn as_str(&self) -> &'static str {
-//         match self {
-//             EntriesQuery::Fields(_) => "fields",
-//             EntriesQuery::GrammaticalFeatures(_) => "grammaticalFeatures",
-//             EntriesQuery::LexicalCategory(_) => "lexicalCategory",
-//             EntriesQuery::Domains(_) => "domains",
-//             EntriesQuery::Registers(_) => "registers",
-//             EntriesQuery::StrictMatch(_) => "strictMatch",
-//         }
-//     }
-// }
-
-#[derive(Debug)]
-/// Main interface to the API wrapper
+#[derive(Debug, Clone)]
+/// Main interface to the API wrapper, clones share the
+/// [`RateLimiter`](RateLimiter) and [`Capabilities`](Capabilities)
 /// ## Example
 /**```no_run
 # fn main(){
 # futures::executor::block_on(run());
 # }
 # async fn run(){
-use oxdi::{apis, Language, apis::entries::{Param, Field}};
+use oxdi::{Language, apis::{EntriesQuery, Field}};
 let client = oxdi::Client::new("id".to_owned(), "key".to_owned(),
     Language::EnGb);
 // or
 // let resp: serde_json::Value
-let resp: oxdi::models::RetrieveEntry = client.entries("crab", 
-    &[Param::Fields(&[
-            Field::Pronunciations,
-        ])
-    ]).await.expect("handle errors");
+let query = EntriesQuery::new().field(Field::Pronunciations);
+let resp: oxdi::models::RetrieveEntry = client.entries("crab", &query)
+    .await.expect("handle errors");
 # }
 ```*/
 pub struct Client {
@@ -115,55 +232,287 @@ pub struct Client {
     app_key: String,
     source_language: languages::Language,
     http_client: surf::Client,
+    base_url: Url,
+    headers: Vec<(String, String)>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
+    cache_policy: CachePolicy,
+    disk_cache: Option<DiskCache>,
+    offline: bool,
+    strict: bool,
+    capabilities: Arc<RwLock<Capabilities>>,
 }
 
 impl Client {
-    /// Creates new instance of `Client`
+    /// Creates new instance of `Client` with the default settings,
+    /// see [`ClientBuilder`](ClientBuilder) to customize them
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be initialized, use
+    /// [`ClientBuilder::build`](ClientBuilder::build) to handle the error
     pub fn new(app_id: String, app_key: String, source_language: Language) -> Self {
-        let mut http_client = surf::Client::new();
-        http_client.set_base_url(Url::parse(API_BASE_URL).unwrap());
-        Self {
-            app_id,
-            app_key,
-            source_language,
-            http_client,
+        Self::builder(app_id, app_key, source_language)
+            .build()
+            .expect("failed to initialize the HTTP client")
+    }
+
+    /// Shortcut for [`ClientBuilder::new`](ClientBuilder::new)
+    pub fn builder(app_id: String, app_key: String, source_language: Language) -> ClientBuilder {
+        ClientBuilder::new(app_id, app_key, source_language)
+    }
+
+    /// Loads the capabilities from the `/languages` endpoint, they are
+    /// used instead of the hard-coded tables by the subsequent requests
+    pub async fn refresh_capabilities(&self) -> Result<Capabilities> {
+        let languages: models::RetrieveLanguages = self.languages().await?;
+        let capabilities = Capabilities::from_datasets(&languages.results);
+        trace!(?capabilities, "loaded capabilities");
+        self.set_capabilities(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    /// Capabilities used to check support of the APIs
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities.read().unwrap().clone()
+    }
+
+    /// Replaces the capabilities, e.g. with the previously
+    /// loaded ones
+    pub fn set_capabilities(&self, capabilities: Capabilities) {
+        *self.capabilities.write().unwrap() = capabilities;
+    }
+
+    /// Rate limiter of the requests, can be used to inspect
+    /// the remaining budget
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Response cache, can be used to inspect the counters
+    /// or invalidate the responses
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
+
+    /// Persistent response cache
+    pub fn disk_cache(&self) -> Option<&DiskCache> {
+        self.disk_cache.as_ref()
+    }
+
+    /// Checks whether the client serves responses only from the caches,
+    /// see [`ClientBuilder::offline`](ClientBuilder::offline)
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Checks whether the responses must match the models exactly,
+    /// see [`ClientBuilder::strict`](ClientBuilder::strict)
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Clone of the client which uses the cache according to the `policy`,
+    /// e.g. `client.with_cache_policy(CachePolicy::Refresh).entries(..)`
+    pub fn with_cache_policy(&self, policy: CachePolicy) -> Client {
+        Client {
+            cache_policy: policy,
+            ..self.clone()
         }
     }
 
-    /// Query all available grammatical features of
-    /// the language  
-    pub async fn grammatical_features(&self) -> Result<serde_json::Value> {
-        let endpoint = Endpoint::GrammaticalFeatures;
-        let path = format!("{}/{}", endpoint.as_str(), self.source_language.code());
-        let mut resp = self.get_request(&path).send().await?;
-        trace!(?endpoint, ?path, "requesting grammatical_features API");
+    /// Checks whether the source language has a dictionary
+    fn has_dictionary(&self) -> bool {
+        self.capabilities
+            .read()
+            .unwrap()
+            .has_dictionary(self.source_language)
+    }
+
+    /// Checks whether the source language can be translated to any language
+    fn has_translations(&self) -> bool {
+        self.capabilities
+            .read()
+            .unwrap()
+            .has_translations(self.source_language)
+    }
+
+    /// Checks whether the source language has a thesaurus
+    fn has_thesaurus(&self) -> bool {
+        self.capabilities
+            .read()
+            .unwrap()
+            .has_thesaurus(self.source_language)
+    }
+
+    /// Checks whether the source language has example sentences
+    fn has_sentences(&self) -> bool {
+        self.capabilities
+            .read()
+            .unwrap()
+            .has_sentences(self.source_language)
+    }
+
+    /// Checks whether the source language can be translated to `target`
+    fn translates_to(&self, target: Language) -> bool {
+        self.capabilities
+            .read()
+            .unwrap()
+            .translates(self.source_language, target)
+    }
 
-        Client::handle_api_error(&mut resp).await?;
-        Ok(resp.body_json().await?)
+    /// Sends GET request to the `path` and deserializes
+    /// the response body
+    async fn get_json<D: DeserializeOwned>(&self, path: &str) -> Result<D> {
+        let body = self.get_body(path).await?;
+        if self.strict {
+            return models::extra::from_str_strict(&body).map_err(|err| Error::SchemaMismatch {
+                path: path.to_owned(),
+                json_path: err.path().to_string(),
+                source: err.into_inner(),
+            });
+        }
+        models::extra::from_str_tracked(&body).map_err(|err| Error::Decode {
+            path: path.to_owned(),
+            json_path: err.path().to_string(),
+            body: snippet(&body).to_owned(),
+            source: err.into_inner(),
+        })
+    }
+    /// Response body of the successful request to the `path`,
+    /// served from the caches if possible
+    async fn get_body(&self, path: &str) -> Result<Arc<str>> {
+        let (cache, disk_cache) = match self.cache_policy {
+            CachePolicy::Bypass => (None, None),
+            CachePolicy::Use | CachePolicy::Refresh => {
+                (self.cache.as_ref(), self.disk_cache.as_ref())
+            }
+        };
+        // offline there is nothing to refresh from
+        if self.cache_policy == CachePolicy::Use || self.offline {
+            if let Some(body) = cache.and_then(|cache| cache.get(path)) {
+                trace!(path, "cache hit");
+                return Ok(body);
+            }
+            if let Some(body) = disk_cache.and_then(|disk_cache| disk_cache.get(path)) {
+                trace!(path, "disk cache hit");
+                let body: Arc<str> = body.into();
+                if let Some(cache) = cache {
+                    cache.insert(path, body.clone());
+                }
+                return Ok(body);
+            }
+        }
+        if self.offline {
+            return Err(Error::OfflineCacheMiss {
+                path: path.to_owned(),
+            });
+        }
+        let mut resp = self.send_with_retry(path).await?;
+        Client::handle_api_error(path, &mut resp).await?;
+        let body: Arc<str> = resp.body_string().await?.into();
+        if let Some(cache) = cache {
+            cache.insert(path, body.clone());
+        }
+        if let Some(disk_cache) = disk_cache {
+            if let Err(err) = disk_cache.insert(path, &body) {
+                warn!(%err, path, "failed to store the response on disk");
+            }
+        }
+        Ok(body)
+    }
+    /// Sends GET request to the `path` repeating it according
+    /// to the [`RetryPolicy`](RetryPolicy)
+    async fn send_with_retry(&self, path: &str) -> Result<surf::Response> {
+        let policy = &self.retry_policy;
+        let mut attempt = 1;
+        loop {
+            if let Some(rate_limiter) = self.rate_limiter.as_ref() {
+                rate_limiter.acquire().await;
+            }
+            let result = self.get_request(path)?.send().await;
+            let retry_after = match &result {
+                Ok(resp) if policy.is_retryable(resp.status()) => {
+                    let x_request_id = resp
+                        .header("X-Request-Id")
+                        .map(|id| id.last().as_str().to_owned());
+                    warn!(attempt, status = ?resp.status(), ?x_request_id, path, "request failed");
+                    resp.header("Retry-After").and_then(|value| {
+                        retry::parse_retry_after(value.as_str(), SystemTime::now())
+                    })
+                }
+                Ok(_) => return Ok(result?),
+                Err(err) => {
+                    warn!(attempt, %err, path, "request failed");
+                    None
+                }
+            };
+            if attempt >= policy.max_attempts {
+                return Ok(result?);
+            }
+            let backoff = policy.backoff(attempt, retry_after);
+            trace!(attempt, ?backoff, "retrying request");
+            futures_timer::Delay::new(backoff).await;
+            attempt += 1;
+        }
     }
-    /// Builds [`RequestBuilder`](surf::RequestBuilder) and 
-    /// sets up headers 
-    fn get_request(&self, uri: &str) -> surf::RequestBuilder {
+    /// Builds [`RequestBuilder`](surf::RequestBuilder) and
+    /// sets up headers
+    fn get_request(&self, uri: &str) -> Result<surf::RequestBuilder> {
         trace!("GET request to: {}", uri);
-        self.http_client
-            .get(uri)
+        let url = self.base_url.join(uri).map_err(Error::InvalidBaseUrl)?;
+        let mut request = self
+            .http_client
+            .get(url)
             .header("app_id", &self.app_id)
             .header("app_key", &self.app_key)
-            .header("Accept", "application/json")
+            .header("Accept", "application/json");
+        for (name, value) in &self.headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        Ok(request)
     }
-    async fn handle_api_error(resp: &mut surf::Response) -> Result<()> {
+    async fn handle_api_error(path: &str, resp: &mut surf::Response) -> Result<()> {
         trace!("checking for API errors");
         let status = resp.status();
-        if [400, 404, 414, 500].contains(&(status as u16)) {
-            // FIXME: add path to the error?
-            let explanation: ErrorExplanation = resp.body_json().await?;
-            let x_request_id = resp.header("X-Request-Id").unwrap().to_string();
-            error!(?explanation, ?x_request_id, ?status);
-            return Err(Error::ApiReturnedError {
-                explanation,
-                x_request_id,
-            });
+        if !status.is_client_error() && !status.is_server_error() {
+            return Ok(());
         }
-        Ok(())
+        let x_request_id = resp
+            .header("X-Request-Id")
+            .map(|id| id.last().as_str().to_owned());
+        let retry_after = resp
+            .header("Retry-After")
+            .and_then(|value| retry::parse_retry_after(value.as_str(), SystemTime::now()));
+        let body = resp.body_string().await.unwrap_or_default();
+        let details = ApiErrorDetails {
+            path: path.to_owned(),
+            x_request_id,
+            explanation: serde_json::from_str(&body).ok(),
+        };
+        error!(?details, ?status);
+        Err(match status {
+            surf::StatusCode::BadRequest => Error::BadRequest(details),
+            surf::StatusCode::Unauthorized => Error::Unauthorized(details),
+            surf::StatusCode::Forbidden => Error::Forbidden(details),
+            surf::StatusCode::NotFound => Error::NotFound(details),
+            surf::StatusCode::UriTooLong => Error::TooLong(details),
+            surf::StatusCode::TooManyRequests => Error::RateLimited {
+                retry_after,
+                details,
+            },
+            status if status.is_server_error() => Error::ServerError { status, details },
+            status => Error::HttpStatus { status, details },
+        })
+    }
+}
+
+/// Beginning of the `body` to keep in errors
+fn snippet(body: &str) -> &str {
+    const MAX_LEN: usize = 256;
+    match body.char_indices().nth(MAX_LEN) {
+        Some((end, _)) => &body[..end],
+        None => body,
     }
 }
@@ -0,0 +1,943 @@
+//! Borrowed variants of the [`entries`](super::entries) models.
+//!
+//! The strings are borrowed from the input buffer when they have
+//! no escapes, which avoids most of the allocations when parsing
+//! many responses, e.g. from the [`DiskCache`](crate::DiskCache).
+//! The models convert to the owned ones with `From`.
+//! ## Example
+/*!```
+use oxdi::models::{borrowed, RetrieveEntry};
+let json = br#"{"results": [{"id": "crab", "language": "en-gb", "lexicalEntries": [
+    {"language": "en-gb", "lexicalCategory": {"id": "noun", "text": "Noun"}, "text": "crab"}
+], "word": "crab"}]}"#;
+let entry = borrowed::RetrieveEntry::from_slice(json).unwrap();
+let headword = &entry.results.as_ref().unwrap()[0];
+assert!(matches!(headword.id, std::borrow::Cow::Borrowed("crab")));
+
+let owned = RetrieveEntry::from(entry);
+assert_eq!(owned.results.unwrap()[0].id, "crab");
+```*/
+use super::entries;
+use super::extra::Extra;
+use super::ids::{GrammaticalFeatureType, LexicalCategoryId, RegionId, RegisterId};
+use super::metadata::Metadata;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetrieveEntry<'a> {
+    /// Id of the requested word
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<HeadwordEntry<'a>>>,
+    /// The requested word
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub word: Option<Cow<'a, str>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeadwordEntry<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub language: Cow<'a, str>,
+    #[serde(rename = "lexicalEntries", borrow)]
+    pub lexical_entries: Vec<LexicalEntry<'a>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation<'a>>>,
+    #[serde(
+        rename = "type",
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub type_: Option<Cow<'a, str>>,
+    #[deprecated = "TODO: find out reason"]
+    #[serde(borrow)]
+    pub word: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexicalEntry<'a> {
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub compounds: Option<Vec<RelatedEntry<'a>>>,
+    #[serde(
+        rename = "derivativeOf",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub derivative_of: Option<Vec<RelatedEntry<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub derivatives: Option<Vec<RelatedEntry<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<Entry<'a>>>,
+    #[serde(
+        rename = "grammaticalFeatures",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature<'a>>>,
+    #[serde(borrow)]
+    pub language: Cow<'a, str>,
+    #[serde(rename = "lexicalCategory", borrow)]
+    pub lexical_category: LexicalCategory<'a>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText<'a>>>,
+    #[serde(
+        rename = "phrasalVerbs",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub phrasal_verbs: Option<Vec<RelatedEntry<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub phrases: Option<Vec<RelatedEntry<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation<'a>>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub root: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    #[serde(
+        rename = "variantForms",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub variant_forms: Option<Vec<VariantForm<'a>>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pronunciation<'a> {
+    #[serde(
+        rename = "audioFile",
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub audio_file: Option<Cow<'a, str>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dialects: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        rename = "phoneticNotation",
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub phonetic_notation: Option<Cow<'a, str>>,
+    #[serde(
+        rename = "phoneticSpelling",
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub phonetic_spelling: Option<Cow<'a, str>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelatedEntry<'a> {
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain<'a>>>,
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<Cow<'a, str>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry<'a> {
+    #[serde(
+        rename = "crossReferenceMarkers",
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cross_reference_markers: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        rename = "crossReferences",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cross_references: Option<Vec<CrossReference<'a>>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub etymologies: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        rename = "grammaticalFeatures",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature<'a>>>,
+    #[serde(
+        rename = "homographNumber",
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub homograph_number: Option<Cow<'a, str>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub inflections: Option<Vec<InflectedForm<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub senses: Option<Vec<Sense<'a>>>,
+    #[serde(
+        rename = "variantForms",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub variant_forms: Option<Vec<VariantForm<'a>>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GrammaticalFeature<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    #[serde(rename = "type")]
+    pub type_: GrammaticalFeatureType,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexicalCategory<'a> {
+    pub id: LexicalCategoryId,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategorizedText<'a> {
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    pub type_: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VariantForm<'a> {
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CrossReference<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    pub type_: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InflectedForm<'a> {
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain<'a>>>,
+    #[serde(
+        rename = "grammaticalFeatures",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature<'a>>>,
+    #[serde(rename = "inflectedForm", borrow)]
+    pub inflected_form: Cow<'a, str>,
+    #[serde(
+        rename = "lexicalCategory",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lexical_category: Option<LexicalCategory<'a>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sense<'a> {
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub antonyms: Option<Vec<SynonymAntonym<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub constructions: Option<Vec<InlineModel2<'a>>>,
+    #[serde(
+        rename = "crossReferenceMarkers",
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cross_reference_markers: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        rename = "crossReferences",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cross_references: Option<Vec<CrossReference<'a>>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub definitions: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        rename = "domainClasses",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub domain_classes: Option<Vec<DomainClass<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain<'a>>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub etymologies: Option<Vec<Cow<'a, str>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<Example<'a>>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub inflections: Option<Vec<InflectedForm<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    #[serde(
+        rename = "semanticClasses",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub semantic_classes: Option<Vec<SemanticClass<'a>>>,
+    #[serde(
+        rename = "shortDefinitions",
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub short_definitions: Option<Vec<Cow<'a, str>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub subsenses: Option<Vec<Sense<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub synonyms: Option<Vec<SynonymAntonym<'a>>>,
+    #[serde(
+        rename = "thesaurusLinks",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub thesaurus_links: Option<Vec<ThesaurusLink<'a>>>,
+    #[serde(
+        rename = "variantForms",
+        borrow,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub variant_forms: Option<Vec<VariantForm<'a>>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InlineModel2<'a> {
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<ExampleText<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SynonymAntonym<'a> {
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain<'a>>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub id: Option<Cow<'a, str>>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_text",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<Cow<'a, str>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExampleText<'a>(#[serde(borrow, deserialize_with = "texts")] pub Vec<Cow<'a, str>>);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Domain<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Region<'a> {
+    pub id: RegionId,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Register<'a> {
+    pub id: RegisterId,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainClass<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Example<'a> {
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub definitions: Option<Vec<Cow<'a, str>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region<'a>>>,
+    #[serde(borrow, skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register<'a>>>,
+    #[serde(
+        rename = "senseIds",
+        borrow,
+        default,
+        deserialize_with = "option_texts",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sense_ids: Option<Vec<Cow<'a, str>>>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SemanticClass<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub text: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThesaurusLink<'a> {
+    #[serde(borrow)]
+    pub entry_id: Cow<'a, str>,
+    #[serde(borrow)]
+    pub sense_id: Cow<'a, str>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+impl<'a> RetrieveEntry<'a> {
+    /// Deserializes the entry borrowing the strings from the `json`
+    pub fn from_slice(json: &'a [u8]) -> serde_json::Result<Self> {
+        serde_json::from_slice(json)
+    }
+}
+
+impl From<RetrieveEntry<'_>> for entries::RetrieveEntry {
+    fn from(model: RetrieveEntry<'_>) -> Self {
+        Self {
+            id: model.id.map(Cow::into_owned),
+            metadata: model.metadata,
+            results: model.results.map(owned),
+            word: model.word.map(Cow::into_owned),
+            extra: model.extra,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<HeadwordEntry<'_>> for entries::HeadwordEntry {
+    fn from(model: HeadwordEntry<'_>) -> Self {
+        Self {
+            id: model.id.into_owned(),
+            language: model.language.into_owned(),
+            lexical_entries: owned(model.lexical_entries),
+            pronunciations: model.pronunciations.map(owned),
+            type_: model.type_.map(Cow::into_owned),
+            word: model.word.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<LexicalEntry<'_>> for entries::LexicalEntry {
+    fn from(model: LexicalEntry<'_>) -> Self {
+        Self {
+            compounds: model.compounds.map(owned),
+            derivative_of: model.derivative_of.map(owned),
+            derivatives: model.derivatives.map(owned),
+            entries: model.entries.map(owned),
+            grammatical_features: model.grammatical_features.map(owned),
+            language: model.language.into_owned(),
+            lexical_category: model.lexical_category.into(),
+            notes: model.notes.map(owned),
+            phrasal_verbs: model.phrasal_verbs.map(owned),
+            phrases: model.phrases.map(owned),
+            pronunciations: model.pronunciations.map(owned),
+            root: model.root.map(Cow::into_owned),
+            text: model.text.into_owned(),
+            variant_forms: model.variant_forms.map(owned),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<Pronunciation<'_>> for entries::Pronunciation {
+    fn from(model: Pronunciation<'_>) -> Self {
+        Self {
+            audio_file: model.audio_file.map(Cow::into_owned),
+            dialects: model.dialects.map(owned_texts),
+            phonetic_notation: model.phonetic_notation.map(Cow::into_owned),
+            phonetic_spelling: model.phonetic_spelling.map(Cow::into_owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<RelatedEntry<'_>> for entries::RelatedEntry {
+    fn from(model: RelatedEntry<'_>) -> Self {
+        Self {
+            domains: model.domains.map(owned),
+            id: model.id.into_owned(),
+            language: model.language.map(Cow::into_owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<Entry<'_>> for entries::Entry {
+    fn from(model: Entry<'_>) -> Self {
+        Self {
+            cross_reference_markers: model.cross_reference_markers.map(owned_texts),
+            cross_references: model.cross_references.map(owned),
+            etymologies: model.etymologies.map(owned_texts),
+            grammatical_features: model.grammatical_features.map(owned),
+            homograph_number: model.homograph_number.map(Cow::into_owned),
+            inflections: model.inflections.map(owned),
+            notes: model.notes.map(owned),
+            pronunciations: model.pronunciations.map(owned),
+            senses: model.senses.map(owned),
+            variant_forms: model.variant_forms.map(owned),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<GrammaticalFeature<'_>> for entries::GrammaticalFeature {
+    fn from(model: GrammaticalFeature<'_>) -> Self {
+        Self {
+            id: model.id.into_owned(),
+            text: model.text.into_owned(),
+            type_: model.type_,
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<LexicalCategory<'_>> for entries::LexicalCategory {
+    fn from(model: LexicalCategory<'_>) -> Self {
+        Self {
+            id: model.id,
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<CategorizedText<'_>> for entries::CategorizedText {
+    fn from(model: CategorizedText<'_>) -> Self {
+        Self {
+            id: model.id.map(Cow::into_owned),
+            text: model.text.into_owned(),
+            type_: model.type_.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<VariantForm<'_>> for entries::VariantForm {
+    fn from(model: VariantForm<'_>) -> Self {
+        Self {
+            domains: model.domains.map(owned),
+            notes: model.notes.map(owned),
+            pronunciations: model.pronunciations.map(owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<CrossReference<'_>> for entries::CrossReference {
+    fn from(model: CrossReference<'_>) -> Self {
+        Self {
+            id: model.id.into_owned(),
+            text: model.text.into_owned(),
+            type_: model.type_.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<InflectedForm<'_>> for entries::InflectedForm {
+    fn from(model: InflectedForm<'_>) -> Self {
+        Self {
+            domains: model.domains.map(owned),
+            grammatical_features: model.grammatical_features.map(owned),
+            inflected_form: model.inflected_form.into_owned(),
+            lexical_category: model.lexical_category.map(Into::into),
+            pronunciations: model.pronunciations.map(owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<Sense<'_>> for entries::Sense {
+    fn from(model: Sense<'_>) -> Self {
+        Self {
+            antonyms: model.antonyms.map(owned),
+            constructions: model.constructions.map(owned),
+            cross_reference_markers: model.cross_reference_markers.map(owned_texts),
+            cross_references: model.cross_references.map(owned),
+            definitions: model.definitions.map(owned_texts),
+            domain_classes: model.domain_classes.map(owned),
+            domains: model.domains.map(owned),
+            etymologies: model.etymologies.map(owned_texts),
+            examples: model.examples.map(owned),
+            id: model.id.map(Cow::into_owned),
+            inflections: model.inflections.map(owned),
+            notes: model.notes.map(owned),
+            pronunciations: model.pronunciations.map(owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            semantic_classes: model.semantic_classes.map(owned),
+            short_definitions: model.short_definitions.map(owned_texts),
+            subsenses: model.subsenses.map(owned),
+            synonyms: model.synonyms.map(owned),
+            thesaurus_links: model.thesaurus_links.map(owned),
+            variant_forms: model.variant_forms.map(owned),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<InlineModel2<'_>> for entries::InlineModel2 {
+    fn from(model: InlineModel2<'_>) -> Self {
+        Self {
+            domains: model.domains.map(owned),
+            examples: model.examples.map(owned),
+            notes: model.notes.map(owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<SynonymAntonym<'_>> for entries::SynonymAntonym {
+    fn from(model: SynonymAntonym<'_>) -> Self {
+        Self {
+            domains: model.domains.map(owned),
+            id: model.id.map(Cow::into_owned),
+            language: model.language.map(Cow::into_owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<ExampleText<'_>> for entries::ExampleText {
+    fn from(text: ExampleText<'_>) -> Self {
+        Self(owned_texts(text.0))
+    }
+}
+
+impl From<Domain<'_>> for entries::Domain {
+    fn from(model: Domain<'_>) -> Self {
+        Self {
+            id: model.id.into_owned(),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<Region<'_>> for entries::Region {
+    fn from(model: Region<'_>) -> Self {
+        Self {
+            id: model.id,
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<Register<'_>> for entries::Register {
+    fn from(model: Register<'_>) -> Self {
+        Self {
+            id: model.id,
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<DomainClass<'_>> for entries::DomainClass {
+    fn from(model: DomainClass<'_>) -> Self {
+        Self {
+            id: model.id.into_owned(),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<Example<'_>> for entries::Example {
+    fn from(model: Example<'_>) -> Self {
+        Self {
+            definitions: model.definitions.map(owned_texts),
+            domains: model.domains.map(owned),
+            notes: model.notes.map(owned),
+            regions: model.regions.map(owned),
+            registers: model.registers.map(owned),
+            sense_ids: model.sense_ids.map(owned_texts),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<SemanticClass<'_>> for entries::SemanticClass {
+    fn from(model: SemanticClass<'_>) -> Self {
+        Self {
+            id: model.id.into_owned(),
+            text: model.text.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+impl From<ThesaurusLink<'_>> for entries::ThesaurusLink {
+    fn from(model: ThesaurusLink<'_>) -> Self {
+        Self {
+            entry_id: model.entry_id.into_owned(),
+            sense_id: model.sense_id.into_owned(),
+            extra: model.extra,
+        }
+    }
+}
+
+/// Serde borrows only the bare `Cow<str>` fields, the nested
+/// ones are wrapped to borrow too
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct Text<'a>(#[serde(borrow)] Cow<'a, str>);
+
+fn option_text<'de: 'a, 'a, D: Deserializer<'de>>(d: D) -> Result<Option<Cow<'a, str>>, D::Error> {
+    Ok(Option::<Text<'a>>::deserialize(d)?.map(|text| text.0))
+}
+
+fn texts<'de: 'a, 'a, D: Deserializer<'de>>(d: D) -> Result<Vec<Cow<'a, str>>, D::Error> {
+    let texts = Vec::<Text<'a>>::deserialize(d)?;
+    Ok(texts.into_iter().map(|text| text.0).collect())
+}
+
+fn option_texts<'de: 'a, 'a, D: Deserializer<'de>>(
+    d: D,
+) -> Result<Option<Vec<Cow<'a, str>>>, D::Error> {
+    let texts = Option::<Vec<Text<'a>>>::deserialize(d)?;
+    Ok(texts.map(|texts| texts.into_iter().map(|text| text.0).collect()))
+}
+
+fn owned<T: Into<U>, U>(models: Vec<T>) -> Vec<U> {
+    models.into_iter().map(Into::into).collect()
+}
+
+fn owned_texts(texts: Vec<Cow<'_, str>>) -> Vec<String> {
+    texts.into_iter().map(Cow::into_owned).collect()
+}
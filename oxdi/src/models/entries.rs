@@ -1,239 +1,389 @@
-use serde::Deserialize;
-use serde_json::Value;
+use super::extra::Extra;
+use super::ids::{GrammaticalFeatureType, LexicalCategoryId, RegionId, RegisterId};
+use super::metadata::Metadata;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RetrieveEntry {
-    pub metadata: Option<Value>,
+    /// Id of the requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<Vec<HeadwordEntry>>,
+    /// The requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HeadwordEntry {
     pub id: String,
     pub language: String,
     #[serde(rename = "lexicalEntries")]
     pub lexical_entries: Vec<LexicalEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pronunciations: Option<Vec<Pronunciation>>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub type_: Option<String>,
     #[deprecated = "TODO: find out reason"]
     pub word: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LexicalEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub compounds: Option<Vec<RelatedEntry>>,
-    #[serde(rename = "derivativeOf")]
+    #[serde(rename = "derivativeOf", skip_serializing_if = "Option::is_none")]
     pub derivative_of: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub derivatives: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub entries: Option<Vec<Entry>>,
-    #[serde(rename = "grammaticalFeatures")]
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub grammatical_features: Option<Vec<GrammaticalFeature>>,
     pub language: String,
     #[serde(rename = "lexicalCategory")]
     pub lexical_category: LexicalCategory,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<CategorizedText>>,
-    #[serde(rename = "phrasalVerbs")]
+    #[serde(rename = "phrasalVerbs", skip_serializing_if = "Option::is_none")]
     pub phrasal_verbs: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phrases: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub root: Option<String>,
     pub text: String,
-    #[serde(rename = "variantForms")]
+    #[serde(rename = "variantForms", skip_serializing_if = "Option::is_none")]
     pub variant_forms: Option<Vec<VariantForm>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Pronunciation {
-    #[serde(rename = "audioFile")]
+    #[serde(rename = "audioFile", skip_serializing_if = "Option::is_none")]
     pub audio_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dialects: Option<Vec<String>>,
-    #[serde(rename = "phoneticNotation")]
+    #[serde(rename = "phoneticNotation", skip_serializing_if = "Option::is_none")]
     pub phonetic_notation: Option<String>,
-    #[serde(rename = "phoneticSpelling")]
+    #[serde(rename = "phoneticSpelling", skip_serializing_if = "Option::is_none")]
     pub phonetic_spelling: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RelatedEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domains: Option<Vec<Domain>>,
     pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Entry {
-    #[serde(rename = "crossReferenceMarkers")]
+    #[serde(
+        rename = "crossReferenceMarkers",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cross_reference_markers: Option<Vec<String>>,
-    #[serde(rename = "crossReferences")]
+    #[serde(rename = "crossReferences", skip_serializing_if = "Option::is_none")]
     pub cross_references: Option<Vec<CrossReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub etymologies: Option<Vec<String>>,
-    #[serde(rename = "grammaticalFeatures")]
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub grammatical_features: Option<Vec<GrammaticalFeature>>,
-    #[serde(rename = "homographNumber")]
+    #[serde(rename = "homographNumber", skip_serializing_if = "Option::is_none")]
     pub homograph_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inflections: Option<Vec<InflectedForm>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub senses: Option<Vec<Sense>>,
-    #[serde(rename = "variantForms")]
+    #[serde(rename = "variantForms", skip_serializing_if = "Option::is_none")]
     pub variant_forms: Option<Vec<VariantForm>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GrammaticalFeature {
     pub id: String,
     pub text: String,
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: GrammaticalFeatureType,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LexicalCategory {
-    pub id: String,
+    pub id: LexicalCategoryId,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategorizedText {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     pub text: String,
     #[serde(rename = "type")]
     pub type_: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VariantForm {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CrossReference {
     pub id: String,
     pub text: String,
     #[serde(rename = "type")]
     pub type_: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InflectedForm {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domains: Option<Vec<Domain>>,
-    #[serde(rename = "grammaticalFeatures")]
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub grammatical_features: Option<Vec<GrammaticalFeature>>,
     #[serde(rename = "inflectedForm")]
     pub inflected_form: String,
-    #[serde(rename = "lexicalCategory")]
+    #[serde(rename = "lexicalCategory", skip_serializing_if = "Option::is_none")]
     pub lexical_category: Option<LexicalCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Sense {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub antonyms: Option<Vec<SynonymAntonym>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub constructions: Option<Vec<InlineModel2>>,
-    #[serde(rename = "crossReferenceMarkers")]
+    #[serde(
+        rename = "crossReferenceMarkers",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub cross_reference_markers: Option<Vec<String>>,
-    #[serde(rename = "crossReferences")]
+    #[serde(rename = "crossReferences", skip_serializing_if = "Option::is_none")]
     pub cross_references: Option<Vec<CrossReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub definitions: Option<Vec<String>>,
-    #[serde(rename = "domainClasses")]
+    #[serde(rename = "domainClasses", skip_serializing_if = "Option::is_none")]
     pub domain_classes: Option<Vec<DomainClass>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub etymologies: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub examples: Option<Vec<Example>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inflections: Option<Vec<InflectedForm>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
-    #[serde(rename = "semanticClasses")]
+    #[serde(rename = "semanticClasses", skip_serializing_if = "Option::is_none")]
     pub semantic_classes: Option<Vec<SemanticClass>>,
-    #[serde(rename = "shortDefinitions")]
+    #[serde(rename = "shortDefinitions", skip_serializing_if = "Option::is_none")]
     pub short_definitions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subsenses: Option<Vec<Sense>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub synonyms: Option<Vec<SynonymAntonym>>,
-    #[serde(rename = "thesaurusLinks")]
+    #[serde(rename = "thesaurusLinks", skip_serializing_if = "Option::is_none")]
     pub thesaurus_links: Option<Vec<ThesaurusLink>>,
-    #[serde(rename = "variantForms")]
+    #[serde(rename = "variantForms", skip_serializing_if = "Option::is_none")]
     pub variant_forms: Option<Vec<VariantForm>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InlineModel2 {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub examples: Option<Vec<ExampleText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SynonymAntonym {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ExampleText(Vec<String>);
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExampleText(pub Vec<String>);
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Domain {
     pub id: String,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Region {
-    pub id: String,
+    pub id: RegionId,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Register {
-    pub id: String,
+    pub id: RegisterId,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DomainClass {
     pub id: String,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Example {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub definitions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub registers: Option<Vec<Register>>,
-    #[serde(rename = "senseIds")]
+    #[serde(rename = "senseIds", skip_serializing_if = "Option::is_none")]
     pub sense_ids: Option<Vec<String>>,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SemanticClass {
     pub id: String,
     pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThesaurusLink {
     pub entry_id: String,
     pub sense_id: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
 }
@@ -0,0 +1,60 @@
+//! Fields of the responses unknown to the models
+use serde::de::{DeserializeOwned, Deserializer, Error as _};
+use serde::Deserialize;
+use serde_json::Value;
+use serde_path_to_error::Track;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+
+/// Unknown fields keyed by their names, kept as is
+pub type Extra = BTreeMap<String, Value>;
+
+thread_local! {
+    /// Whether unknown fields fail the deserialization on this thread
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Restores the previous strict mode even if the deserialization panics,
+/// so that a nested strict deserialization doesn't end the outer one
+struct StrictGuard(bool);
+
+impl Drop for StrictGuard {
+    fn drop(&mut self) {
+        STRICT.with(|strict| strict.set(self.0));
+    }
+}
+
+/// Deserializes the `json` failing on unknown fields and type
+/// mismatches, the error contains the path of the diverged field
+pub(crate) fn from_str_strict<T: DeserializeOwned>(
+    json: &str,
+) -> Result<T, serde_path_to_error::Error<serde_json::Error>> {
+    let _guard = StrictGuard(STRICT.with(|strict| strict.replace(true)));
+    from_str_tracked(json)
+}
+
+/// Deserializes the `json` keeping unknown fields in the `extra` maps,
+/// the error contains the path of the failed field
+pub(crate) fn from_str_tracked<T: DeserializeOwned>(
+    json: &str,
+) -> Result<T, serde_path_to_error::Error<serde_json::Error>> {
+    let mut de = serde_json::Deserializer::from_str(json);
+    let value = serde_path_to_error::deserialize(&mut de)?;
+    // trailing characters after the top level value
+    de.end()
+        .map_err(|err| serde_path_to_error::Error::new(Track::new().path(), err))?;
+    Ok(value)
+}
+
+/// Deserializer of the flattened `extra` fields
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Extra, D::Error> {
+    let extra = Extra::deserialize(d)?;
+    if !extra.is_empty() && STRICT.with(Cell::get) {
+        let names: Vec<&str> = extra.keys().map(String::as_str).collect();
+        return Err(D::Error::custom(format_args!(
+            "unknown fields `{}`",
+            names.join("`, `")
+        )));
+    }
+    Ok(extra)
+}
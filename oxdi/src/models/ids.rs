@@ -0,0 +1,226 @@
+//! Identifiers of the labels used by the API, the ids
+//! unknown to the crate are kept in the `Other` variant
+//! ## Example
+/*!```
+use oxdi::models::{LexicalCategoryId, RegisterId};
+assert_eq!(LexicalCategoryId::from("noun"), LexicalCategoryId::Noun);
+assert_eq!(RegisterId::VulgarSlang.as_str(), "vulgar_slang");
+assert_eq!(
+    RegisterId::from("new_register"),
+    RegisterId::Other("new_register".to_owned())
+);
+```*/
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Visitor of the ids, the known ones are parsed without allocations
+struct IdVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for IdVisitor<T>
+where
+    T: for<'a> From<&'a str> + From<String>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an id")
+    }
+
+    fn visit_str<E: de::Error>(self, id: &str) -> Result<T, E> {
+        Ok(T::from(id))
+    }
+
+    fn visit_string<E: de::Error>(self, id: String) -> Result<T, E> {
+        Ok(T::from(id))
+    }
+}
+
+macro_rules! ids {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $id:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        ///
+        /// Ids are compared, ordered and hashed by [`as_str`](Self::as_str),
+        /// so a known id equals the same id in the `Other` variant
+        #[derive(Debug, Clone)]
+        pub enum $name {
+            $($variant,)*
+            /// Id unknown to the crate
+            Other(String),
+        }
+
+        impl $name {
+            /// Id as used by the API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $id,)*
+                    $name::Other(id) => id,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                match id {
+                    $($id => $name::$variant,)*
+                    other => $name::Other(other.to_owned()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                match $name::from(id.as_str()) {
+                    $name::Other(_) => $name::Other(id),
+                    known => known,
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state)
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.as_str().cmp(other.as_str())
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                d.deserialize_str(IdVisitor(PhantomData))
+            }
+        }
+    };
+}
+
+ids! {
+    /// Id of the lexical category (part of speech)
+    pub enum LexicalCategoryId {
+        Adjective => "adjective",
+        Adverb => "adverb",
+        CombiningForm => "combining_form",
+        Conjunction => "conjunction",
+        Contraction => "contraction",
+        Determiner => "determiner",
+        Idiomatic => "idiomatic",
+        Interjection => "interjection",
+        Noun => "noun",
+        Numeral => "numeral",
+        Particle => "particle",
+        Predeterminer => "predeterminer",
+        Prefix => "prefix",
+        Preposition => "preposition",
+        Pronoun => "pronoun",
+        Residual => "residual",
+        Suffix => "suffix",
+        Verb => "verb",
+    }
+}
+
+ids! {
+    /// Type of the grammatical feature, e.g. `Number` for `plural`
+    pub enum GrammaticalFeatureType {
+        Aspect => "Aspect",
+        Case => "Case",
+        Degree => "Degree",
+        Form => "Form",
+        Gender => "Gender",
+        Mood => "Mood",
+        NonInflectional => "Non Inflectional",
+        Number => "Number",
+        Person => "Person",
+        Subcategorization => "Subcategorization",
+        Tense => "Tense",
+        Voice => "Voice",
+    }
+}
+
+ids! {
+    /// Id of the register (level of formality, attitude)
+    pub enum RegisterId {
+        Archaic => "archaic",
+        Dated => "dated",
+        Derogatory => "derogatory",
+        Dialect => "dialect",
+        Euphemistic => "euphemistic",
+        Formal => "formal",
+        Historical => "historical",
+        Humorous => "humorous",
+        Informal => "informal",
+        Ironic => "ironic",
+        Literary => "literary",
+        Offensive => "offensive",
+        Rare => "rare",
+        Slang => "slang",
+        Technical => "technical",
+        VulgarSlang => "vulgar_slang",
+    }
+}
+
+ids! {
+    /// Id of the region where the word or sense is used
+    pub enum RegionId {
+        Australian => "australian",
+        British => "british",
+        Canadian => "canadian",
+        Caribbean => "caribbean",
+        EastAfrican => "east_african",
+        Indian => "indian",
+        Irish => "irish",
+        NewZealand => "new_zealand",
+        NorthAmerican => "north_american",
+        NorthernEnglish => "northern_english",
+        Scottish => "scottish",
+        SouthAfrican => "south_african",
+        Us => "us",
+        WestAfrican => "west_african",
+    }
+}
@@ -0,0 +1,142 @@
+use super::entries::{GrammaticalFeature, InflectedForm, LexicalCategory};
+use super::extra::Extra;
+use super::metadata::Metadata;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetrieveInflections {
+    /// Id of the requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<HeadwordInflections>>,
+    /// The requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeadwordInflections {
+    pub id: String,
+    pub language: String,
+    #[serde(rename = "lexicalEntries")]
+    pub lexical_entries: Vec<InflectionsLexicalEntry>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    pub word: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InflectionsLexicalEntry {
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature>>,
+    pub inflections: Vec<InflectedForm>,
+    pub language: String,
+    #[serde(rename = "lexicalCategory")]
+    pub lexical_category: LexicalCategory,
+    pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+impl InflectionsLexicalEntry {
+    /// Groups the inflected forms into a [`Paradigm`](Paradigm)
+    pub fn paradigm(&self) -> Paradigm<'_> {
+        Paradigm::new(&self.inflections)
+    }
+}
+
+/// Inflected forms grouped by their grammatical features.
+///
+/// Every feature type (e.g. `Number`, `Case`) that occurs in the forms
+/// is an axis of the table, a cell is keyed by the feature ids
+/// (e.g. `plural`, `genitive`) in the order of the axes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Paradigm<'a> {
+    /// Sorted feature types
+    pub axes: Vec<&'a str>,
+    /// Forms keyed by feature ids, an empty id means that
+    /// the form has no feature of that type
+    pub cells: BTreeMap<Vec<&'a str>, Vec<&'a str>>,
+}
+
+impl<'a> Paradigm<'a> {
+    pub fn new(forms: &'a [InflectedForm]) -> Self {
+        let mut axes: Vec<&str> = forms
+            .iter()
+            .flat_map(|form| form.grammatical_features.iter().flatten())
+            .map(|feature| feature.type_.as_str())
+            .collect();
+        axes.sort_unstable();
+        axes.dedup();
+
+        let mut cells: BTreeMap<Vec<&str>, Vec<&str>> = BTreeMap::new();
+        for form in forms {
+            let key = axes
+                .iter()
+                .map(|axis| {
+                    form.grammatical_features
+                        .iter()
+                        .flatten()
+                        .find(|feature| feature.type_ == *axis)
+                        .map_or("", |feature| feature.id.as_str())
+                })
+                .collect();
+            let cell = cells.entry(key).or_default();
+            if !cell.contains(&form.inflected_form.as_str()) {
+                cell.push(&form.inflected_form);
+            }
+        }
+        Self { axes, cells }
+    }
+
+    /// Forms of the cell, `key` is feature ids in the order of
+    /// the [`axes`](Paradigm::axes)
+    pub fn get<'s>(&'s self, key: &[&'s str]) -> Option<&'s [&'a str]> {
+        // the keys are shortened to the `key` lifetime, the map is covariant
+        let cells: &'s BTreeMap<Vec<&'s str>, Vec<&'a str>> = &self.cells;
+        cells.get(key).map(Vec::as_slice)
+    }
+}
+
+/// Formats the paradigm as a table with a column per axis
+/// and the forms in the last column
+impl fmt::Display for Paradigm<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // widths in characters, not bytes, for the non-ASCII ids
+        let width_of = |id: &str| id.chars().count();
+        let mut widths: Vec<usize> = self.axes.iter().map(|axis| width_of(axis)).collect();
+        for key in self.cells.keys() {
+            for (width, id) in widths.iter_mut().zip(key) {
+                *width = (*width).max(width_of(id));
+            }
+        }
+
+        for (axis, width) in self.axes.iter().zip(&widths) {
+            write!(f, "{:width$} | ", axis, width = width)?;
+        }
+        writeln!(f, "Forms")?;
+        for (key, forms) in &self.cells {
+            for (id, width) in key.iter().zip(&widths) {
+                let id = if id.is_empty() { "-" } else { id };
+                write!(f, "{:width$} | ", id, width = width)?;
+            }
+            writeln!(f, "{}", forms.join(", "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use super::entries::{GrammaticalFeature, LexicalCategory};
+use super::extra::Extra;
+use super::metadata::Metadata;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lemmatron {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<HeadwordLemmatron>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+impl Lemmatron {
+    /// Unique ids of all lemmas in the order of appearance
+    pub fn lemmas(&self) -> Vec<&str> {
+        let mut lemmas: Vec<&str> = Vec::new();
+        let inflections = self
+            .results
+            .iter()
+            .flatten()
+            .flat_map(|headword| &headword.lexical_entries)
+            .flat_map(|lexical_entry| &lexical_entry.inflection_of);
+        for inflection in inflections {
+            if !lemmas.contains(&inflection.id.as_str()) {
+                lemmas.push(&inflection.id);
+            }
+        }
+        lemmas
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeadwordLemmatron {
+    pub id: String,
+    pub language: String,
+    #[serde(rename = "lexicalEntries")]
+    pub lexical_entries: Vec<LemmatronLexicalEntry>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    pub word: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LemmatronLexicalEntry {
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature>>,
+    #[serde(rename = "inflectionOf")]
+    pub inflection_of: Vec<InflectionOf>,
+    pub language: String,
+    #[serde(rename = "lexicalCategory")]
+    pub lexical_category: LexicalCategory,
+    pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InflectionOf {
+    pub id: String,
+    pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
@@ -0,0 +1,18 @@
+use super::extra::Extra;
+use serde::{Deserialize, Serialize};
+
+/// Information about the response and its provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Name of the operation, e.g. `retrieve`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Name of the response schema, e.g. `RetrieveEntry`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
@@ -1,3 +1,28 @@
+pub mod borrowed;
 pub mod entries;
+pub mod extra;
+pub mod ids;
+pub mod inflections;
+pub mod lemmas;
+pub mod metadata;
+pub mod search;
+pub mod sentences;
+pub mod simple;
+pub mod thesaurus;
+pub mod translations;
+pub mod utility;
+pub mod visit;
 
 pub use entries::RetrieveEntry;
+pub use extra::Extra;
+pub use ids::{GrammaticalFeatureType, LexicalCategoryId, RegionId, RegisterId};
+pub use inflections::RetrieveInflections;
+pub use lemmas::Lemmatron;
+pub use metadata::Metadata;
+pub use search::SearchResults;
+pub use sentences::SentencesResults;
+pub use simple::{SimpleEntry, SimpleLexicalEntry};
+pub use thesaurus::RetrieveThesaurus;
+pub use translations::RetrieveTranslation;
+pub use utility::{EndpointNames, Labels, RetrieveLanguages};
+pub use visit::{Context, SenseNumber, Visitor, Walk};
@@ -0,0 +1,102 @@
+use super::entries::{CategorizedText, Domain, LexicalCategory, Region, Register};
+use super::extra::Extra;
+use super::ids::RegionId;
+use super::metadata::Metadata;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentencesResults {
+    /// Id of the requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<HeadwordSentences>>,
+    /// The requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+impl SentencesResults {
+    /// All sentences of all headwords
+    pub fn sentences(&self) -> impl Iterator<Item = &Sentence> {
+        self.results
+            .iter()
+            .flatten()
+            .flat_map(|headword| &headword.lexical_entries)
+            .flat_map(|lexical_entry| &lexical_entry.sentences)
+    }
+
+    /// Sentences grouped by the sense ids, a sentence
+    /// may belong to several senses
+    pub fn by_sense(&self) -> BTreeMap<&str, Vec<&Sentence>> {
+        let mut groups: BTreeMap<&str, Vec<&Sentence>> = BTreeMap::new();
+        for sentence in self.sentences() {
+            for sense_id in sentence.sense_ids.iter().flatten() {
+                groups.entry(sense_id).or_default().push(sentence);
+            }
+        }
+        groups
+    }
+
+    /// Sentences grouped by the region ids (e.g. `british`),
+    /// a sentence may belong to several regions
+    pub fn by_region(&self) -> BTreeMap<&RegionId, Vec<&Sentence>> {
+        let mut groups: BTreeMap<&RegionId, Vec<&Sentence>> = BTreeMap::new();
+        for sentence in self.sentences() {
+            for region in sentence.regions.iter().flatten() {
+                groups.entry(&region.id).or_default().push(sentence);
+            }
+        }
+        groups
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeadwordSentences {
+    pub id: String,
+    pub language: String,
+    #[serde(rename = "lexicalEntries")]
+    pub lexical_entries: Vec<SentencesLexicalEntry>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    pub word: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentencesLexicalEntry {
+    pub language: String,
+    #[serde(rename = "lexicalCategory")]
+    pub lexical_category: LexicalCategory,
+    pub sentences: Vec<Sentence>,
+    pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sentence {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register>>,
+    #[serde(rename = "senseIds", skip_serializing_if = "Option::is_none")]
+    pub sense_ids: Option<Vec<String>>,
+    pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
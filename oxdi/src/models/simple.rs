@@ -0,0 +1,145 @@
+//! Flattened projection of the [`RetrieveEntry`](RetrieveEntry)
+//! with the most used parts of it
+use super::entries::{HeadwordEntry, LexicalEntry, RetrieveEntry};
+use super::ids::LexicalCategoryId;
+use super::visit::Walk;
+use serde::{Deserialize, Serialize};
+
+/// Word with its parts of speech, IPA, short definitions and examples.
+/// ## Example
+/**```
+use oxdi::models::{RetrieveEntry, SimpleEntry};
+let entry: RetrieveEntry = serde_json::from_str(r#"{"results": [{
+    "id": "crab",
+    "language": "en-gb",
+    "lexicalEntries": [{
+        "entries": [{
+            "pronunciations": [{"phoneticNotation": "IPA", "phoneticSpelling": "kræb"}],
+            "senses": [{
+                "definitions": ["a crustacean with a broad carapace"],
+                "examples": [{"text": "crabs and lobsters"}],
+                "shortDefinitions": ["crustacean with broad carapace"]
+            }]
+        }],
+        "language": "en-gb",
+        "lexicalCategory": {"id": "noun", "text": "Noun"},
+        "text": "crab"
+    }],
+    "word": "crab"
+}]}"#).unwrap();
+let simple = SimpleEntry::from(&entry);
+assert_eq!(
+    serde_json::to_value(&simple).unwrap(),
+    serde_json::json!({
+        "word": "crab",
+        "lexicalEntries": [{
+            "partOfSpeech": "noun",
+            "ipa": ["kræb"],
+            "definitions": ["crustacean with broad carapace"],
+            "examples": ["crabs and lobsters"]
+        }]
+    })
+);
+```*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimpleEntry {
+    pub word: String,
+    #[serde(rename = "lexicalEntries")]
+    pub lexical_entries: Vec<SimpleLexicalEntry>,
+}
+
+/// Word as a part of speech
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimpleLexicalEntry {
+    #[serde(rename = "partOfSpeech")]
+    pub part_of_speech: LexicalCategoryId,
+    /// Unique phonetic spellings in IPA
+    pub ipa: Vec<String>,
+    /// Short definitions of the senses, or the full ones
+    /// for the senses without short definitions
+    pub definitions: Vec<String>,
+    pub examples: Vec<String>,
+}
+
+impl SimpleEntry {
+    /// Examples kept for every part of speech by [`From`](SimpleEntry::from)
+    pub const EXAMPLES: usize = 2;
+
+    /// Projects the `entry` keeping at most `max_examples`
+    /// examples for every part of speech
+    pub fn with_examples(entry: &RetrieveEntry, max_examples: usize) -> Self {
+        let headwords = entry.results.iter().flatten();
+        let lexical_entries = headwords
+            .flat_map(|headword| {
+                let lexical_entries = headword.lexical_entries.iter();
+                lexical_entries.map(move |lexical_entry| {
+                    SimpleLexicalEntry::new(headword, lexical_entry, max_examples)
+                })
+            })
+            .collect();
+        let word = entry
+            .results
+            .iter()
+            .flatten()
+            .flat_map(|headword| headword.lexical_entries.first())
+            .map(|lexical_entry| lexical_entry.text.clone())
+            .next()
+            .or_else(|| entry.word.clone())
+            .unwrap_or_default();
+        Self {
+            word,
+            lexical_entries,
+        }
+    }
+}
+
+impl From<&RetrieveEntry> for SimpleEntry {
+    fn from(entry: &RetrieveEntry) -> Self {
+        Self::with_examples(entry, Self::EXAMPLES)
+    }
+}
+
+impl SimpleLexicalEntry {
+    fn new(headword: &HeadwordEntry, lexical_entry: &LexicalEntry, max_examples: usize) -> Self {
+        // the headword pronunciations are shared by its lexical entries
+        let pronunciations = headword.pronunciations.iter().flatten();
+        let pronunciations = pronunciations.chain(
+            lexical_entry
+                .all_pronunciations()
+                .map(|(_, pronunciation)| pronunciation),
+        );
+        let mut ipa: Vec<String> = Vec::new();
+        for pronunciation in pronunciations {
+            if pronunciation.phonetic_notation.as_deref() != Some("IPA") {
+                continue;
+            }
+            if let Some(spelling) = pronunciation.phonetic_spelling.as_ref() {
+                if !ipa.contains(spelling) {
+                    ipa.push(spelling.clone());
+                }
+            }
+        }
+
+        // subsenses are too detailed for the projection
+        let senses = lexical_entry.all_senses().filter(
+            |(context, _)| !matches!(&context.sense_number, Some(number) if number.is_subsense()),
+        );
+        let mut definitions = Vec::new();
+        let mut examples = Vec::new();
+        for (_, sense) in senses {
+            let short = sense.short_definitions.as_ref();
+            let texts = short
+                .filter(|short| !short.is_empty())
+                .or(sense.definitions.as_ref());
+            definitions.extend(texts.into_iter().flatten().cloned());
+            let texts = sense.examples.iter().flatten().map(|example| &example.text);
+            examples.extend(texts.take(max_examples - examples.len()).cloned());
+        }
+        Self {
+            part_of_speech: lexical_entry.lexical_category.id.clone(),
+            ipa,
+            definitions,
+            examples,
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use super::entries::{
+    Domain, Example, GrammaticalFeature, LexicalCategory, Region, Register, SynonymAntonym,
+    VariantForm,
+};
+use super::extra::Extra;
+use super::metadata::Metadata;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetrieveThesaurus {
+    /// Id of the requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<HeadwordThesaurus>>,
+    /// The requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeadwordThesaurus {
+    pub id: String,
+    pub language: String,
+    #[serde(rename = "lexicalEntries")]
+    pub lexical_entries: Vec<LexicalEntryThesaurus>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    pub word: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexicalEntryThesaurus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<EntryThesaurus>>,
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature>>,
+    pub language: String,
+    #[serde(rename = "lexicalCategory")]
+    pub lexical_category: LexicalCategory,
+    pub text: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntryThesaurus {
+    #[serde(rename = "homographNumber", skip_serializing_if = "Option::is_none")]
+    pub homograph_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub senses: Option<Vec<ThesaurusSense>>,
+    #[serde(rename = "variantForms", skip_serializing_if = "Option::is_none")]
+    pub variant_forms: Option<Vec<VariantForm>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThesaurusSense {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub antonyms: Option<Vec<SynonymAntonym>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<Example>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsenses: Option<Vec<ThesaurusSense>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synonyms: Option<Vec<SynonymAntonym>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
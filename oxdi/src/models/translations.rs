@@ -0,0 +1,242 @@
+use super::entries::{
+    CategorizedText, CrossReference, Domain, DomainClass, GrammaticalFeature, InflectedForm,
+    LexicalCategory, Pronunciation, Region, Register, RelatedEntry, SemanticClass, VariantForm,
+};
+use super::extra::Extra;
+use super::metadata::Metadata;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetrieveTranslation {
+    /// Id of the requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<HeadwordTranslation>>,
+    /// The requested word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeadwordTranslation {
+    pub id: String,
+    pub language: String,
+    #[serde(rename = "lexicalEntries")]
+    pub lexical_entries: Vec<LexicalEntryTranslation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    pub word: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexicalEntryTranslation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compounds: Option<Vec<RelatedEntry>>,
+    #[serde(rename = "derivativeOf", skip_serializing_if = "Option::is_none")]
+    pub derivative_of: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub derivatives: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries: Option<Vec<EntryTranslation>>,
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature>>,
+    pub language: String,
+    #[serde(rename = "lexicalCategory")]
+    pub lexical_category: LexicalCategory,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText>>,
+    #[serde(rename = "phrasalVerbs", skip_serializing_if = "Option::is_none")]
+    pub phrasal_verbs: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phrases: Option<Vec<RelatedEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+    pub text: String,
+    #[serde(rename = "variantForms", skip_serializing_if = "Option::is_none")]
+    pub variant_forms: Option<Vec<VariantForm>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntryTranslation {
+    #[serde(
+        rename = "crossReferenceMarkers",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cross_reference_markers: Option<Vec<String>>,
+    #[serde(rename = "crossReferences", skip_serializing_if = "Option::is_none")]
+    pub cross_references: Option<Vec<CrossReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etymologies: Option<Vec<String>>,
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature>>,
+    #[serde(rename = "homographNumber", skip_serializing_if = "Option::is_none")]
+    pub homograph_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inflections: Option<Vec<InflectedForm>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub senses: Option<Vec<TranslationSense>>,
+    #[serde(rename = "variantForms", skip_serializing_if = "Option::is_none")]
+    pub variant_forms: Option<Vec<VariantForm>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranslationSense {
+    #[serde(
+        rename = "crossReferenceMarkers",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cross_reference_markers: Option<Vec<String>>,
+    #[serde(rename = "crossReferences", skip_serializing_if = "Option::is_none")]
+    pub cross_references: Option<Vec<CrossReference>>,
+    #[serde(rename = "datasetCrossLinks", skip_serializing_if = "Option::is_none")]
+    pub dataset_cross_links: Option<Vec<DatasetCrossLink>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definitions: Option<Vec<String>>,
+    #[serde(rename = "domainClasses", skip_serializing_if = "Option::is_none")]
+    pub domain_classes: Option<Vec<DomainClass>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etymologies: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub examples: Option<Vec<TranslationExample>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronunciations: Option<Vec<Pronunciation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register>>,
+    #[serde(rename = "semanticClasses", skip_serializing_if = "Option::is_none")]
+    pub semantic_classes: Option<Vec<SemanticClass>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsenses: Option<Vec<TranslationSense>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations: Option<Vec<Translation>>,
+    #[serde(rename = "variantForms", skip_serializing_if = "Option::is_none")]
+    pub variant_forms: Option<Vec<VariantForm>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasetCrossLink {
+    pub entry_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sense_id: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Translation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collocations: Option<Vec<Collocation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain>>,
+    #[serde(
+        rename = "grammaticalFeatures",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub grammatical_features: Option<Vec<GrammaticalFeature>>,
+    pub language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register>>,
+    pub text: String,
+    #[serde(rename = "toneGroups", skip_serializing_if = "Option::is_none")]
+    pub tone_groups: Option<Vec<ToneGroup>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranslationExample {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definitions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<Domain>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<CategorizedText>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<Region>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registers: Option<Vec<Register>>,
+    #[serde(rename = "senseIds", skip_serializing_if = "Option::is_none")]
+    pub sense_ids: Option<Vec<String>>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations: Option<Vec<Translation>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Collocation {
+    pub id: String,
+    pub text: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToneGroup {
+    pub tones: Vec<Tone>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tone {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub value: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
@@ -0,0 +1,148 @@
+use super::extra::Extra;
+use super::metadata::Metadata;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Response of the label endpoints (domains, regions, etc.)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawLabels", into = "RawLabels")]
+pub struct Labels {
+    pub metadata: Option<Metadata>,
+    /// Label texts keyed by ids
+    pub texts: BTreeMap<String, String>,
+    /// Label types keyed by ids, only for the labels
+    /// which have one (e.g. grammatical features)
+    pub types: BTreeMap<String, String>,
+    /// Fields of the labels unknown to the crate keyed by ids,
+    /// only for the labels which have them
+    pub extras: BTreeMap<String, Extra>,
+    /// Fields of the response unknown to the crate
+    pub extra: Extra,
+}
+
+impl Labels {
+    /// Human readable text of the label
+    pub fn text(&self, id: &str) -> Option<&str> {
+        self.texts.get(id).map(String::as_str)
+    }
+
+    /// Checks whether `id` is a known label
+    pub fn contains(&self, id: &str) -> bool {
+        self.texts.contains_key(id)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawLabels {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<Metadata>,
+    results: Vec<Label>,
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    extra: Extra,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Label {
+    id: String,
+    text: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    type_: Option<String>,
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    extra: Extra,
+}
+
+impl From<RawLabels> for Labels {
+    fn from(raw: RawLabels) -> Self {
+        let mut texts = BTreeMap::new();
+        let mut types = BTreeMap::new();
+        let mut extras = BTreeMap::new();
+        for label in raw.results {
+            if let Some(type_) = label.type_ {
+                types.insert(label.id.clone(), type_);
+            }
+            if !label.extra.is_empty() {
+                extras.insert(label.id.clone(), label.extra);
+            }
+            texts.insert(label.id, label.text);
+        }
+        Self {
+            metadata: raw.metadata,
+            texts,
+            types,
+            extras,
+            extra: raw.extra,
+        }
+    }
+}
+
+impl From<Labels> for RawLabels {
+    fn from(labels: Labels) -> Self {
+        let mut types = labels.types;
+        let mut extras = labels.extras;
+        let results = labels
+            .texts
+            .into_iter()
+            .map(|(id, text)| Label {
+                type_: types.remove(&id),
+                extra: extras.remove(&id).unwrap_or_default(),
+                id,
+                text,
+            })
+            .collect();
+        Self {
+            metadata: labels.metadata,
+            results,
+            extra: labels.extra,
+        }
+    }
+}
+
+/// Response of the `fields` and `filters` endpoints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointNames {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Names keyed by endpoints (e.g. `entries`)
+    pub results: BTreeMap<String, Vec<String>>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetrieveLanguages {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    pub results: Vec<LanguageDataset>,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageDataset {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    /// Name of the dataset
+    pub source: String,
+    #[serde(rename = "sourceLanguage")]
+    pub source_language: LanguageLabel,
+    #[serde(rename = "targetLanguage", skip_serializing_if = "Option::is_none")]
+    pub target_language: Option<LanguageLabel>,
+    /// `monolingual` or `bilingual`
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageLabel {
+    /// Language code (e.g. `en`)
+    pub id: String,
+    pub language: String,
+    /// Fields unknown to the crate
+    #[serde(flatten, deserialize_with = "super::extra::deserialize")]
+    pub extra: Extra,
+}
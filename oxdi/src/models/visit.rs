@@ -0,0 +1,230 @@
+//! Traversal of the [`entries`](super::entries) models.
+//!
+//! [`Walk`](Walk) visits the headwords, lexical entries, entries, senses
+//! with their subsenses and pronunciations in the order of the response,
+//! every item comes with its [`Context`](Context).
+//! ## Example
+/*!```no_run
+# async fn example(client: oxdi::Client) -> Result<(), oxdi::Error> {
+use oxdi::apis::EntriesQuery;
+use oxdi::models::{RetrieveEntry, Walk};
+let entry: RetrieveEntry = client.entries("crab", &EntriesQuery::new()).await?;
+for (context, definition) in entry.all_definitions() {
+    // e.g. `crab, Noun 1.1.a) the flesh of a crab as food`
+    println!(
+        "{}, {} {}) {}",
+        context.headword,
+        context.lexical_category.unwrap().text,
+        context.sense_number.unwrap(),
+        definition
+    );
+}
+# Ok(())
+# }
+```*/
+use super::entries::{
+    Entry, Example, HeadwordEntry, LexicalCategory, LexicalEntry, Pronunciation, RetrieveEntry,
+    Sense,
+};
+use std::fmt;
+
+/// Where the visited item is in the response
+#[derive(Debug, Clone, PartialEq)]
+pub struct Context<'a> {
+    /// Id of the headword, or the text of the lexical entry
+    /// if the traversal starts from it
+    pub headword: &'a str,
+    /// `None` for the items of the headword itself
+    pub lexical_category: Option<&'a LexicalCategory>,
+    /// `None` for the items outside of the senses
+    pub sense_number: Option<SenseNumber>,
+}
+
+/// Number of the sense within its lexical entry, e.g. `1.2.a` is the first
+/// subsense of the second sense of the first entry (homograph)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SenseNumber(Vec<usize>);
+
+impl SenseNumber {
+    /// One-based positions of the entry, the sense and the subsenses
+    pub fn levels(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Checks whether the sense is a subsense
+    pub fn is_subsense(&self) -> bool {
+        self.0.len() > 2
+    }
+
+    fn child(&self, position: usize) -> Self {
+        let mut levels = self.0.clone();
+        levels.push(position);
+        Self(levels)
+    }
+}
+
+/// Subsenses are lettered, e.g. `1.2.a`, or numbered after `z`
+impl fmt::Display for SenseNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, &level) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(".")?;
+            }
+            match level {
+                1..=26 if i >= 2 => write!(f, "{}", (b'a' + level as u8 - 1) as char)?,
+                _ => write!(f, "{}", level)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Callbacks of the traversal, all of them do nothing by default.
+///
+/// The senses are visited before their subsenses.
+pub trait Visitor<'a> {
+    fn visit_headword(&mut self, _headword: &'a HeadwordEntry) {}
+
+    fn visit_lexical_entry(&mut self, _context: &Context<'a>, _lexical_entry: &'a LexicalEntry) {}
+
+    fn visit_entry(&mut self, _context: &Context<'a>, _entry: &'a Entry) {}
+
+    fn visit_sense(&mut self, _context: &Context<'a>, _sense: &'a Sense) {}
+
+    fn visit_pronunciation(&mut self, _context: &Context<'a>, _pronunciation: &'a Pronunciation) {}
+}
+
+/// Traversal of the models containing senses
+pub trait Walk<'a> {
+    /// Calls the `visitor` for every nested item
+    fn walk<V: Visitor<'a>>(&'a self, visitor: &mut V);
+
+    /// Senses and subsenses
+    fn all_senses(&'a self) -> std::vec::IntoIter<(Context<'a>, &'a Sense)> {
+        let mut senses = Senses(Vec::new());
+        self.walk(&mut senses);
+        senses.0.into_iter()
+    }
+
+    /// Definitions of the senses and subsenses
+    fn all_definitions(&'a self) -> std::vec::IntoIter<(Context<'a>, &'a str)> {
+        self.all_senses()
+            .flat_map(|(context, sense)| {
+                let definitions = sense.definitions.iter().flatten();
+                definitions.map(move |definition| (context.clone(), definition.as_str()))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Examples of the senses and subsenses
+    fn all_examples(&'a self) -> std::vec::IntoIter<(Context<'a>, &'a Example)> {
+        self.all_senses()
+            .flat_map(|(context, sense)| {
+                let examples = sense.examples.iter().flatten();
+                examples.map(move |example| (context.clone(), example))
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Pronunciations of the headwords, lexical entries, entries and senses
+    fn all_pronunciations(&'a self) -> std::vec::IntoIter<(Context<'a>, &'a Pronunciation)> {
+        let mut pronunciations = Pronunciations(Vec::new());
+        self.walk(&mut pronunciations);
+        pronunciations.0.into_iter()
+    }
+}
+
+impl<'a> Walk<'a> for RetrieveEntry {
+    fn walk<V: Visitor<'a>>(&'a self, visitor: &mut V) {
+        for headword in self.results.iter().flatten() {
+            headword.walk(visitor);
+        }
+    }
+}
+
+impl<'a> Walk<'a> for HeadwordEntry {
+    fn walk<V: Visitor<'a>>(&'a self, visitor: &mut V) {
+        visitor.visit_headword(self);
+        let context = Context {
+            headword: &self.id,
+            lexical_category: None,
+            sense_number: None,
+        };
+        for pronunciation in self.pronunciations.iter().flatten() {
+            visitor.visit_pronunciation(&context, pronunciation);
+        }
+        for lexical_entry in &self.lexical_entries {
+            walk_lexical_entry(&self.id, lexical_entry, visitor);
+        }
+    }
+}
+
+impl<'a> Walk<'a> for LexicalEntry {
+    fn walk<V: Visitor<'a>>(&'a self, visitor: &mut V) {
+        walk_lexical_entry(&self.text, self, visitor);
+    }
+}
+
+fn walk_lexical_entry<'a, V: Visitor<'a>>(
+    headword: &'a str,
+    lexical_entry: &'a LexicalEntry,
+    visitor: &mut V,
+) {
+    let context = Context {
+        headword,
+        lexical_category: Some(&lexical_entry.lexical_category),
+        sense_number: None,
+    };
+    visitor.visit_lexical_entry(&context, lexical_entry);
+    for pronunciation in lexical_entry.pronunciations.iter().flatten() {
+        visitor.visit_pronunciation(&context, pronunciation);
+    }
+    for (i, entry) in lexical_entry.entries.iter().flatten().enumerate() {
+        visitor.visit_entry(&context, entry);
+        for pronunciation in entry.pronunciations.iter().flatten() {
+            visitor.visit_pronunciation(&context, pronunciation);
+        }
+        for (j, sense) in entry.senses.iter().flatten().enumerate() {
+            walk_sense(&context, SenseNumber(vec![i + 1, j + 1]), sense, visitor);
+        }
+    }
+}
+
+/// Visits the `sense` and its subsenses, the `context` is
+/// the one of the lexical entry
+fn walk_sense<'a, V: Visitor<'a>>(
+    context: &Context<'a>,
+    number: SenseNumber,
+    sense: &'a Sense,
+    visitor: &mut V,
+) {
+    let sense_context = Context {
+        sense_number: Some(number.clone()),
+        ..context.clone()
+    };
+    visitor.visit_sense(&sense_context, sense);
+    for pronunciation in sense.pronunciations.iter().flatten() {
+        visitor.visit_pronunciation(&sense_context, pronunciation);
+    }
+    for (i, subsense) in sense.subsenses.iter().flatten().enumerate() {
+        walk_sense(context, number.child(i + 1), subsense, visitor);
+    }
+}
+
+struct Senses<'a>(Vec<(Context<'a>, &'a Sense)>);
+
+impl<'a> Visitor<'a> for Senses<'a> {
+    fn visit_sense(&mut self, context: &Context<'a>, sense: &'a Sense) {
+        self.0.push((context.clone(), sense));
+    }
+}
+
+struct Pronunciations<'a>(Vec<(Context<'a>, &'a Pronunciation)>);
+
+impl<'a> Visitor<'a> for Pronunciations<'a> {
+    fn visit_pronunciation(&mut self, context: &Context<'a>, pronunciation: &'a Pronunciation) {
+        self.0.push((context.clone(), pronunciation));
+    }
+}
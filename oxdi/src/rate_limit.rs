@@ -0,0 +1,157 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::trace;
+
+const MONTH: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Client-side token bucket limiter of the requests.
+///
+/// Requests are delayed until the budget allows them instead of failing.
+/// Clones of the limiter (and of the [`Client`](crate::Client) using it)
+/// share the same budget, while [`per_second`](RateLimiter::per_second)
+/// and [`per_month`](RateLimiter::per_month) return a new limiter.
+/// ## Example
+/**```no_run
+use oxdi::{ClientBuilder, Language, RateLimiter};
+let limiter = RateLimiter::new()
+    .per_second(3)
+    .per_month(1000);
+let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+    .rate_limiter(limiter.clone())
+    .build()
+    .expect("handle errors");
+println!("{:?}", limiter.remaining());
+```*/
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Buckets {
+    per_second: Option<Bucket>,
+    per_month: Option<Bucket>,
+}
+
+/// Remaining budget of the [`RateLimiter`](RateLimiter),
+/// `None` when the limit is not set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub per_second: Option<u32>,
+    pub per_month: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    /// Tokens per second
+    refill_rate: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    fn new(capacity: u32, period: Duration) -> Self {
+        let capacity = f64::from(capacity);
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_rate: capacity / period.as_secs_f64(),
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.refill_rate).min(self.capacity);
+        self.refilled_at = now;
+    }
+
+    /// Time until a token is available
+    fn wait_time(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.refill_rate)
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Creates limiter without limits, see [`per_second`](RateLimiter::per_second)
+    /// and [`per_month`](RateLimiter::per_month)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow at most `requests` per second, also the size of a burst.
+    ///
+    /// Returns a new limiter with a copy of the current budget,
+    /// the clones made before are not affected
+    pub fn per_second(self, requests: u32) -> Self {
+        let mut buckets = self.buckets.lock().unwrap().clone();
+        buckets.per_second = Some(Bucket::new(requests.max(1), Duration::from_secs(1)));
+        Self::from_buckets(buckets)
+    }
+
+    /// Allow at most `requests` per 30 days, the budget
+    /// is replenished continuously.
+    ///
+    /// Returns a new limiter with a copy of the current budget,
+    /// the clones made before are not affected
+    pub fn per_month(self, requests: u32) -> Self {
+        let mut buckets = self.buckets.lock().unwrap().clone();
+        buckets.per_month = Some(Bucket::new(requests.max(1), MONTH));
+        Self::from_buckets(buckets)
+    }
+
+    fn from_buckets(buckets: Buckets) -> Self {
+        Self {
+            buckets: Arc::new(Mutex::new(buckets)),
+        }
+    }
+
+    /// Remaining budget
+    pub fn remaining(&self) -> Budget {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        let remaining = |bucket: &mut Option<Bucket>| {
+            bucket.as_mut().map(|bucket| {
+                bucket.refill(now);
+                bucket.tokens as u32
+            })
+        };
+        Budget {
+            per_second: remaining(&mut buckets.per_second),
+            per_month: remaining(&mut buckets.per_month),
+        }
+    }
+
+    /// Waits until the request is allowed and takes it from the budget
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().unwrap();
+                let now = Instant::now();
+                let Buckets {
+                    per_second,
+                    per_month,
+                } = &mut *buckets;
+                let mut wait = Duration::ZERO;
+                for bucket in per_second.iter_mut().chain(per_month.iter_mut()) {
+                    bucket.refill(now);
+                    wait = wait.max(bucket.wait_time());
+                }
+                if wait.is_zero() {
+                    for bucket in per_second.iter_mut().chain(per_month.iter_mut()) {
+                        bucket.tokens -= 1.0;
+                    }
+                    return;
+                }
+                wait
+            };
+            trace!(?wait, "rate limited, delaying request");
+            futures_timer::Delay::new(wait).await;
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use surf::StatusCode;
+
+/// When and how long to wait before repeating a failed request.
+///
+/// Only transport errors and `429`, `500`, `502`, `503`, `504`
+/// responses are retried. All requests of the crate are idempotent
+/// `GET`s, so repeating them is safe.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts including the first one,
+    /// `1` disables retries
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every next one
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay
+    pub max_backoff: Duration,
+    /// Randomize delays so that concurrent clients don't retry in lockstep
+    pub jitter: bool,
+    /// Wait as long as the `Retry-After` header of the response asks
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates policy with the default settings: 3 attempts,
+    /// backoff from 500ms up to 30s with jitter, respecting `Retry-After`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [`max_attempts`](RetryPolicy::max_attempts), at least 1
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the [`initial_backoff`](RetryPolicy::initial_backoff)
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the [`max_backoff`](RetryPolicy::max_backoff)
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the [`jitter`](RetryPolicy::jitter)
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the [`respect_retry_after`](RetryPolicy::respect_retry_after)
+    pub fn respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// Policy which never retries, used by default
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Checks whether the response with the `status` should be retried
+    pub fn is_retryable(&self, status: StatusCode) -> bool {
+        is_retryable_status(status)
+    }
+
+    /// Delay before the next attempt after the failed `attempt` (starting from 1),
+    /// `Retry-After` of the response is capped by the `max_backoff` too
+    pub fn backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let (true, Some(retry_after)) = (self.respect_retry_after, retry_after) {
+            return retry_after.min(self.max_backoff);
+        }
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .initial_backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff));
+        if self.jitter {
+            // "equal jitter": half of the delay is fixed, the other half is random
+            let half = backoff / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Statuses of the responses which may succeed if repeated
+pub(crate) const fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TooManyRequests
+            | StatusCode::InternalServerError
+            | StatusCode::BadGateway
+            | StatusCode::ServiceUnavailable
+            | StatusCode::GatewayTimeout
+    )
+}
+
+/// Parses `Retry-After` in seconds or as an HTTP date, e.g.
+/// `Sun, 06 Nov 1994 08:49:37 GMT`, the dates before `now` give zero delay
+pub fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value)?;
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+/// Parses the preferred HTTP date format (IMF-fixdate)
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut parts = value.split_whitespace();
+    let _weekday = parts.next()?.strip_suffix(',')?;
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTHS.iter().position(|&name| name == month)? as u64 + 1;
+    let year: u64 = parts.next()?.parse().ok()?;
+    let mut time = parts
+        .next()?
+        .split(':')
+        .map(|part| part.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (time.next()??, time.next()??, time.next()??);
+    if parts.next()? != "GMT" || parts.next().is_some() || time.next().is_some() {
+        return None;
+    }
+    if year < 1970 || !(1..=31).contains(&day) || hours > 23 || minutes > 59 || seconds > 60 {
+        return None;
+    }
+
+    // days since the epoch of the civil date, Howard Hinnant's algorithm
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146_097 + day_of_era).checked_sub(719_468)?;
+    let seconds = days * 86_400 + hours * 3_600 + minutes * 60 + seconds;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
@@ -0,0 +1,120 @@
+//! LRU eviction, TTL and counters of the in-memory response cache
+use futures::executor::block_on;
+use oxdi::apis::EntriesQuery;
+use oxdi::cache::CacheStats;
+use oxdi::{ClientBuilder, Error, Language, ResponseCache};
+use std::time::Duration;
+
+fn body(cache: &ResponseCache, path: &str) -> Option<String> {
+    cache.get(path).map(|body| body.to_string())
+}
+
+#[test]
+fn lru_eviction() {
+    let cache = ResponseCache::new(2);
+    cache.insert("a", "1");
+    cache.insert("b", "2");
+    // `a` becomes the most recently used one
+    assert_eq!(body(&cache, "a").as_deref(), Some("1"));
+    cache.insert("c", "3");
+    assert_eq!(body(&cache, "b"), None);
+    assert_eq!(body(&cache, "a").as_deref(), Some("1"));
+    assert_eq!(body(&cache, "c").as_deref(), Some("3"));
+
+    // replacing a response doesn't evict the others
+    cache.insert("c", "4");
+    assert_eq!(cache.stats().len, 2);
+    assert_eq!(body(&cache, "c").as_deref(), Some("4"));
+    // now `a` is the least recently used one
+    cache.insert("d", "5");
+    assert_eq!(body(&cache, "a"), None);
+    assert_eq!(body(&cache, "c").as_deref(), Some("4"));
+
+    let cache = ResponseCache::new(0);
+    cache.insert("a", "1");
+    assert_eq!(body(&cache, "a"), None);
+}
+
+#[test]
+fn ttl() {
+    let cache = ResponseCache::new(10).ttl(Duration::from_millis(50));
+    cache.insert("a", "1");
+    assert_eq!(body(&cache, "a").as_deref(), Some("1"));
+    std::thread::sleep(Duration::from_millis(80));
+    assert_eq!(body(&cache, "a"), None);
+    assert_eq!(
+        cache.stats(),
+        CacheStats {
+            hits: 1,
+            misses: 1,
+            len: 0
+        }
+    );
+}
+
+#[test]
+fn counters() {
+    let cache = ResponseCache::new(10);
+    assert_eq!(body(&cache, "a"), None);
+    cache.insert("a", "1");
+    assert_eq!(body(&cache, "a").as_deref(), Some("1"));
+    assert_eq!(body(&cache, "a").as_deref(), Some("1"));
+    assert_eq!(
+        cache.stats(),
+        CacheStats {
+            hits: 2,
+            misses: 1,
+            len: 1
+        }
+    );
+    // shared by the clones, the counters survive clearing
+    let clone = cache.clone();
+    clone.clear();
+    assert_eq!(
+        cache.stats(),
+        CacheStats {
+            hits: 2,
+            misses: 1,
+            len: 0
+        }
+    );
+}
+
+#[test]
+fn invalidate() {
+    let cache = ResponseCache::new(10);
+    cache.insert("a", "1");
+    cache.insert("b", "2");
+    cache.invalidate("a");
+    cache.invalidate("unknown");
+    assert_eq!(body(&cache, "a"), None);
+    assert_eq!(body(&cache, "b").as_deref(), Some("2"));
+    // the freed slot is reused without evicting `b`
+    let cache = ResponseCache::new(1);
+    cache.insert("a", "1");
+    cache.invalidate("a");
+    cache.insert("b", "2");
+    assert_eq!(body(&cache, "b").as_deref(), Some("2"));
+}
+
+#[test]
+fn client_invalidate_entries() {
+    let cache = ResponseCache::new(10);
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .cache(cache.clone())
+        .offline(true)
+        .build()
+        .unwrap();
+    let query = EntriesQuery::new().strict_match(false);
+    cache.insert(
+        "entries/en-gb/ice_cream?strictMatch=false",
+        r#"{"results": []}"#,
+    );
+    let entry: serde_json::Value = block_on(client.entries("ice cream", &query)).unwrap();
+    assert_eq!(entry["results"], serde_json::json!([]));
+
+    client.invalidate_entries("ice cream", &query);
+    assert_eq!(cache.stats().len, 0);
+    let err = block_on(client.entries::<serde_json::Value>("ice cream", &query)).unwrap_err();
+    assert!(matches!(err, Error::OfflineCacheMiss { .. }));
+}
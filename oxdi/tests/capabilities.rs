@@ -0,0 +1,120 @@
+//! Capabilities loaded from the `/languages` payload and the fallback tables
+use oxdi::apis::EntriesQuery;
+use oxdi::models::RetrieveLanguages;
+use oxdi::{Capabilities, Client, Error, Language};
+use serde_json::json;
+
+fn live() -> Capabilities {
+    let languages: RetrieveLanguages = serde_json::from_value(json!({
+        "results": [
+            {
+                "region": "gb",
+                "source": "Oxford Dictionary of English",
+                "sourceLanguage": {"id": "en", "language": "English"},
+                "type": "monolingual"
+            },
+            {
+                "source": "Oxford Spanish Dictionary",
+                "sourceLanguage": {"id": "es", "language": "Spanish"},
+                "type": "monolingual"
+            },
+            {
+                "source": "Oxford Spanish Dictionary",
+                "sourceLanguage": {"id": "en", "language": "English"},
+                "targetLanguage": {"id": "es", "language": "Spanish"},
+                "type": "bilingual"
+            },
+            {
+                "source": "Klingon Dictionary",
+                "sourceLanguage": {"id": "tlh", "language": "Klingon"},
+                "type": "monolingual"
+            }
+        ]
+    }))
+    .unwrap();
+    Capabilities::from_datasets(&languages.results)
+}
+
+#[test]
+fn monolingual() {
+    let capabilities = live();
+    assert!(capabilities.is_live());
+    assert!(capabilities.has_dictionary(Language::EnGb));
+    // the region restricts the dataset to British English
+    assert!(!capabilities.has_dictionary(Language::EnUs));
+    assert!(capabilities.has_dictionary(Language::Es));
+    // in the hard-coded tables, but not in the payload
+    assert!(!capabilities.has_dictionary(Language::Fr));
+}
+
+#[test]
+fn bilingual() {
+    let capabilities = live();
+    // English without a region is both British and American
+    assert!(capabilities.translates(Language::EnGb, Language::Es));
+    assert!(capabilities.translates(Language::EnUs, Language::Es));
+    assert!(!capabilities.translates(Language::Es, Language::EnGb));
+    assert!(!capabilities.translates(Language::EnGb, Language::De));
+    assert!(capabilities.has_translations(Language::EnGb));
+    assert!(!capabilities.has_translations(Language::Es));
+    assert_eq!(
+        capabilities.target_languages(Language::EnUs),
+        [Language::Es]
+    );
+}
+
+#[test]
+fn thesaurus_and_sentences() {
+    // not described by `/languages`, so the same for both
+    for capabilities in [live(), Capabilities::fallback()] {
+        assert!(capabilities.has_thesaurus(Language::EnGb));
+        assert!(!capabilities.has_thesaurus(Language::Es));
+        assert!(capabilities.has_sentences(Language::Es));
+        assert!(!capabilities.has_sentences(Language::Fr));
+    }
+}
+
+#[test]
+fn fallback() {
+    let capabilities = Capabilities::fallback();
+    assert!(!capabilities.is_live());
+    assert_eq!(Capabilities::default(), capabilities);
+    assert!(capabilities.has_dictionary(Language::Fr));
+    assert!(!capabilities.has_dictionary(Language::Ar));
+    assert!(capabilities.translates(Language::EnGb, Language::De));
+    assert!(capabilities.translates(Language::De, Language::EnUs));
+
+    // nothing usable in the registry
+    assert_eq!(Capabilities::from_datasets(&[]), capabilities);
+    let unknown: RetrieveLanguages = serde_json::from_value(json!({
+        "results": [{
+            "source": "Klingon Dictionary",
+            "sourceLanguage": {"id": "tlh", "language": "Klingon"},
+            "type": "monolingual"
+        }]
+    }))
+    .unwrap();
+    assert_eq!(Capabilities::from_datasets(&unknown.results), capabilities);
+}
+
+#[test]
+fn client_uses_capabilities() {
+    let client = Client::new("id".to_owned(), "key".to_owned(), Language::EnGb);
+    let clone = client.clone();
+    client.set_capabilities(live());
+    // shared by the clones
+    assert!(clone.capabilities().is_live());
+    let err = futures::executor::block_on(clone.translations::<serde_json::Value>(
+        "crab",
+        Language::De,
+        &EntriesQuery::new(),
+    ))
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::UnsupportedLanguagePair {
+            source_language: Language::EnGb,
+            target_language: Language::De,
+        }
+    ));
+}
@@ -0,0 +1,139 @@
+//! File names, writes and offline mode of the on-disk response cache
+use futures::executor::block_on;
+use oxdi::apis::EntriesQuery;
+use oxdi::{ClientBuilder, DiskCache, Error, Language};
+use std::fs;
+use std::path::PathBuf;
+
+/// Empty directory unique to the test
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("oxdi-disk-cache-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn files(cache: &DiskCache) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(cache.dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn file_names() {
+    let dir = temp_dir("names");
+    let cache = DiskCache::new(&dir).unwrap();
+    cache
+        .insert(
+            "entries/en-gb/ice_cream?fields=definitions%2Cexamples",
+            "{}",
+        )
+        .unwrap();
+    assert_eq!(
+        files(&cache),
+        ["entries%2Fen-gb%2Fice_cream%3Ffields%3Ddefinitions%252%43examples.json"]
+    );
+
+    // too long names are shortened and suffixed with the stable hash of the path
+    let long = format!("entries/en-gb/{}", "a".repeat(300));
+    cache.insert(&long, "{}").unwrap();
+    let expected = format!(
+        "entries%2Fen-gb%2F{}~c312e775cc57d760.json",
+        "a".repeat(200 - 17 - "entries%2Fen-gb%2F".len())
+    );
+    assert!(files(&cache).contains(&expected));
+    // paths with the same beginning don't collide
+    let longer = format!("{}b", long);
+    cache.insert(&longer, "{\"b\": 1}").unwrap();
+    assert_eq!(cache.get(&long).as_deref(), Some("{}"));
+    assert_eq!(cache.get(&longer).as_deref(), Some("{\"b\": 1}"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn case_insensitive_names() {
+    let dir = temp_dir("case");
+    let cache = DiskCache::new(&dir).unwrap();
+    cache.insert("entries/en-gb/Polish", "\"Polish\"").unwrap();
+    cache.insert("entries/en-gb/polish", "\"polish\"").unwrap();
+    // the names differ even if the case is ignored
+    assert_eq!(
+        files(&cache),
+        [
+            "entries%2Fen-gb%2F%50olish.json",
+            "entries%2Fen-gb%2Fpolish.json"
+        ]
+    );
+    assert_eq!(
+        cache.get("entries/en-gb/Polish").as_deref(),
+        Some("\"Polish\"")
+    );
+    assert_eq!(
+        cache.get("entries/en-gb/polish").as_deref(),
+        Some("\"polish\"")
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn writes() {
+    let dir = temp_dir("writes");
+    let cache = DiskCache::new(&dir).unwrap();
+    assert_eq!(cache.get("entries/en-gb/crab"), None);
+    // leftover of an interrupted write
+    fs::write(dir.join("entries%2Fen-gb%2Fcrab.json.tmp"), "{\"res").unwrap();
+    assert_eq!(cache.get("entries/en-gb/crab"), None);
+
+    cache
+        .insert("entries/en-gb/crab", "{\"results\": 1}")
+        .unwrap();
+    cache
+        .insert("entries/en-gb/crab", "{\"results\": 2}")
+        .unwrap();
+    // the temporary file is renamed over the response
+    assert_eq!(files(&cache), ["entries%2Fen-gb%2Fcrab.json"]);
+    assert_eq!(
+        cache.get("entries/en-gb/crab").as_deref(),
+        Some("{\"results\": 2}")
+    );
+
+    cache.invalidate("entries/en-gb/crab").unwrap();
+    cache.invalidate("entries/en-gb/crab").unwrap();
+    assert_eq!(cache.get("entries/en-gb/crab"), None);
+    cache.insert("entries/en-gb/crab", "{}").unwrap();
+    cache.clear().unwrap();
+    assert!(files(&cache).is_empty());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn offline() {
+    let dir = temp_dir("offline");
+    let query = EntriesQuery::new();
+    {
+        let cache = DiskCache::new(&dir).unwrap();
+        cache
+            .insert("entries/en-gb/crab", r#"{"results": [], "word": "crab"}"#)
+            .unwrap();
+    }
+
+    // a new client reloads the responses from the directory
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .disk_cache(DiskCache::new(&dir).unwrap())
+        .offline(true)
+        .build()
+        .unwrap();
+    assert!(client.is_offline());
+    let entry: serde_json::Value = block_on(client.entries("crab", &query)).unwrap();
+    assert_eq!(entry["word"], "crab");
+
+    let err = block_on(client.entries::<serde_json::Value>("lobster", &query)).unwrap_err();
+    assert!(
+        matches!(err, Error::OfflineCacheMiss { ref path } if path == "entries/en-gb/lobster"),
+        "{:?}",
+        err
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
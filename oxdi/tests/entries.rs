@@ -0,0 +1,589 @@
+//! Models of the Entries API against the recorded responses in `tests/fixtures`
+use futures::executor::block_on;
+use oxdi::apis::{normalize_word_id, EntriesQuery, Field};
+use oxdi::fixtures::{FixtureClient, FixtureMode};
+use oxdi::models::entries::*;
+use oxdi::models::{Context, LexicalCategoryId, SimpleEntry, Visitor, Walk};
+use oxdi::{Client, ClientBuilder, Error, Language, ResponseCache};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+fn client(lang: Language) -> Client {
+    ClientBuilder::new("id".to_owned(), "key".to_owned(), lang)
+        .fixtures(FIXTURES, FixtureMode::Replay)
+        .build()
+        .unwrap()
+}
+
+fn headword(lang: Language, word: &str, query: &EntriesQuery) -> HeadwordEntry {
+    let entry: RetrieveEntry = block_on(client(lang).entries(word, query)).unwrap();
+    assert!(entry.metadata.is_some());
+    entry.results.unwrap().into_iter().next().unwrap()
+}
+
+#[test]
+fn headword_and_lexical_entries() {
+    let crab = headword(Language::EnGb, "crab", &EntriesQuery::new());
+    assert_eq!(crab.id, "crab");
+    assert_eq!(crab.language, "en-gb");
+    assert_eq!(crab.type_.as_deref(), Some("headword"));
+    assert_eq!(crab.lexical_entries.len(), 2);
+
+    let noun = &crab.lexical_entries[0];
+    assert_eq!(noun.lexical_category.id, "noun");
+    assert_eq!(noun.lexical_category.text, "Noun");
+    assert_eq!(noun.text, "crab");
+    assert_eq!(noun.language, "en-gb");
+    let compounds = noun.compounds.as_ref().unwrap();
+    assert_eq!(compounds[0].id, "crab_apple");
+    assert_eq!(compounds[1].language.as_deref(), Some("en"));
+    assert_eq!(noun.derivatives.as_ref().unwrap()[0].text, "crablike");
+    assert_eq!(noun.phrases.as_ref().unwrap()[0].id, "catch_a_crab");
+
+    let verb = &crab.lexical_entries[1];
+    assert_eq!(verb.lexical_category.id, "verb");
+    let features = verb.entries.as_ref().unwrap()[0]
+        .grammatical_features
+        .as_ref()
+        .unwrap();
+    assert_eq!(features[0].id, "transitive");
+    assert_eq!(features[0].type_, "Subcategorization");
+}
+
+#[test]
+fn entry_pronunciations_and_inflections() {
+    let crab = headword(Language::EnGb, "crab", &EntriesQuery::new());
+    let entry = &crab.lexical_entries[0].entries.as_ref().unwrap()[0];
+    assert_eq!(entry.homograph_number.as_deref(), Some("100"));
+    assert!(entry.etymologies.as_ref().unwrap()[0].starts_with("Old English"));
+
+    let pronunciation = &entry.pronunciations.as_ref().unwrap()[0];
+    assert_eq!(pronunciation.phonetic_spelling.as_deref(), Some("kræb"));
+    assert_eq!(pronunciation.phonetic_notation.as_deref(), Some("IPA"));
+    assert_eq!(
+        pronunciation.dialects.as_ref().unwrap()[0],
+        "British English"
+    );
+    assert!(pronunciation.audio_file.as_ref().unwrap().ends_with(".mp3"));
+
+    let inflection = &entry.inflections.as_ref().unwrap()[0];
+    assert_eq!(inflection.inflected_form, "crabs");
+    assert_eq!(
+        inflection.grammatical_features.as_ref().unwrap()[0].type_,
+        "Number"
+    );
+}
+
+#[test]
+fn senses() {
+    let crab = headword(Language::EnGb, "crab", &EntriesQuery::new());
+    let senses = crab.lexical_entries[0].entries.as_ref().unwrap()[0]
+        .senses
+        .as_ref()
+        .unwrap();
+
+    let sense = &senses[0];
+    assert_eq!(sense.id.as_deref(), Some("m_en_gbus0233580.006"));
+    assert!(sense.definitions.as_ref().unwrap()[0].starts_with("a crustacean"));
+    assert_eq!(sense.short_definitions.as_ref().unwrap().len(), 1);
+    assert_eq!(sense.domain_classes.as_ref().unwrap()[0].id, "crustacean");
+    assert_eq!(
+        sense.semantic_classes.as_ref().unwrap()[0].text,
+        "Crustacean"
+    );
+    assert_eq!(sense.synonyms.as_ref().unwrap()[0].text, "crustacean");
+    let link = &sense.thesaurus_links.as_ref().unwrap()[0];
+    assert_eq!(link.entry_id, "crab");
+    assert_eq!(link.sense_id, "t_en_gb0003402.001");
+    assert_eq!(
+        sense.examples.as_ref().unwrap()[0].text,
+        "fishermen in the area catch crabs and lobsters"
+    );
+
+    let subsenses = sense.subsenses.as_ref().unwrap();
+    assert_eq!(subsenses.len(), 2);
+    assert_eq!(
+        subsenses[0].notes.as_ref().unwrap()[0].type_,
+        "grammaticalNote"
+    );
+    assert_eq!(subsenses[1].domains.as_ref().unwrap()[0].id, "astrology");
+
+    let louse = &senses[1];
+    assert_eq!(
+        louse.cross_reference_markers.as_ref().unwrap()[0],
+        "short for crab louse"
+    );
+    let reference = &louse.cross_references.as_ref().unwrap()[0];
+    assert_eq!(reference.id, "crab_louse");
+    assert_eq!(reference.type_, "abbreviation of");
+    assert_eq!(louse.registers.as_ref().unwrap()[0].id, "informal");
+}
+
+#[test]
+fn constructions() {
+    let crab = headword(Language::EnGb, "crab", &EntriesQuery::new());
+    let sense = &crab.lexical_entries[1].entries.as_ref().unwrap()[0]
+        .senses
+        .as_ref()
+        .unwrap()[0];
+    let construction = &sense.constructions.as_ref().unwrap()[0];
+    assert_eq!(construction.text, "crab across");
+    assert_eq!(
+        construction.examples.as_ref().unwrap()[0].0,
+        ["the boat crabbed across the river"]
+    );
+    assert_eq!(sense.domains.as_ref().unwrap()[0].text, "Nautical");
+}
+
+#[test]
+fn variant_forms_and_notes() {
+    let colour = headword(
+        Language::EnGb,
+        "colour",
+        &EntriesQuery::new().strict_match(false),
+    );
+    let entry = &colour.lexical_entries[0].entries.as_ref().unwrap()[0];
+    assert_eq!(
+        entry.cross_reference_markers.as_ref().unwrap()[0],
+        "see also color"
+    );
+    assert_eq!(
+        entry.cross_references.as_ref().unwrap()[0].type_,
+        "see also"
+    );
+    let note = &entry.notes.as_ref().unwrap()[0];
+    assert_eq!(note.id.as_deref(), Some("n_colour_1"));
+    assert_eq!(note.type_, "editorialNote");
+    assert_eq!(
+        entry.pronunciations.as_ref().unwrap()[0]
+            .regions
+            .as_ref()
+            .unwrap()[0]
+            .id,
+        "british"
+    );
+    assert_eq!(entry.variant_forms.as_ref().unwrap()[0].text, "color");
+
+    let sense = &entry.senses.as_ref().unwrap()[0];
+    let variant = &sense.variant_forms.as_ref().unwrap()[0];
+    assert_eq!(variant.text, "color");
+    assert_eq!(variant.domains.as_ref().unwrap()[0].id, "art");
+    assert_eq!(variant.notes.as_ref().unwrap()[0].text, "rare");
+    assert_eq!(variant.regions.as_ref().unwrap()[0].id, "us");
+    assert_eq!(variant.registers.as_ref().unwrap()[0].id, "archaic");
+    assert_eq!(
+        variant.pronunciations.as_ref().unwrap()[0]
+            .phonetic_spelling
+            .as_deref(),
+        Some("ˈkʌlər")
+    );
+    assert_eq!(sense.regions.as_ref().unwrap()[0].text, "British");
+    assert!(sense.etymologies.as_ref().unwrap()[0].starts_with("Middle English"));
+    assert_eq!(
+        sense.pronunciations.as_ref().unwrap()[0]
+            .registers
+            .as_ref()
+            .unwrap()[0]
+            .id,
+        "informal"
+    );
+}
+
+#[test]
+fn antonyms_examples_and_inflected_forms() {
+    let colour = headword(
+        Language::EnGb,
+        "colour",
+        &EntriesQuery::new().strict_match(false),
+    );
+    let sense = &colour.lexical_entries[0].entries.as_ref().unwrap()[0]
+        .senses
+        .as_ref()
+        .unwrap()[0];
+
+    let antonym = &sense.antonyms.as_ref().unwrap()[0];
+    assert_eq!(antonym.id.as_deref(), Some("monochrome"));
+    assert_eq!(antonym.language.as_deref(), Some("en"));
+    assert_eq!(antonym.domains.as_ref().unwrap()[0].id, "art");
+    assert_eq!(antonym.regions.as_ref().unwrap()[0].id, "british");
+    assert_eq!(antonym.registers.as_ref().unwrap()[0].id, "technical");
+
+    let example = &sense.examples.as_ref().unwrap()[0];
+    assert_eq!(example.text, "the lights flickered and changed colour");
+    assert_eq!(
+        example.definitions.as_ref().unwrap()[0],
+        "in the sense of hue"
+    );
+    assert_eq!(example.domains.as_ref().unwrap()[0].id, "art");
+    assert_eq!(example.notes.as_ref().unwrap()[0].text, "figurative");
+    assert_eq!(example.regions.as_ref().unwrap()[0].id, "british");
+    assert_eq!(example.registers.as_ref().unwrap()[0].id, "literary");
+    assert_eq!(
+        example.sense_ids.as_ref().unwrap()[0],
+        "m_en_gbus0205150.006"
+    );
+
+    let inflection = &sense.inflections.as_ref().unwrap()[0];
+    assert_eq!(inflection.inflected_form, "colours");
+    assert_eq!(inflection.lexical_category.as_ref().unwrap().id, "noun");
+    assert_eq!(inflection.domains.as_ref().unwrap()[0].id, "art");
+    assert_eq!(inflection.regions.as_ref().unwrap()[0].id, "british");
+    assert_eq!(inflection.registers.as_ref().unwrap()[0].id, "dated");
+    assert_eq!(
+        inflection.pronunciations.as_ref().unwrap()[0]
+            .phonetic_spelling
+            .as_deref(),
+        Some("ˈkʌləz")
+    );
+
+    let phrasal_verbs = colour.lexical_entries[1].phrasal_verbs.as_ref().unwrap();
+    assert_eq!(phrasal_verbs[0].id, "colour_in");
+    assert_eq!(phrasal_verbs[1].regions.as_ref().unwrap()[0].id, "british");
+    assert_eq!(phrasal_verbs[1].registers.as_ref().unwrap()[0].id, "dated");
+}
+
+#[test]
+fn derivatives_and_lexical_entry_fields() {
+    let colorful = headword(Language::EnUs, "colorful", &EntriesQuery::new());
+    assert_eq!(
+        colorful.pronunciations.as_ref().unwrap()[0]
+            .phonetic_spelling
+            .as_deref(),
+        Some("ˈkʌlərfəl")
+    );
+
+    let lexical_entry = &colorful.lexical_entries[0];
+    assert_eq!(lexical_entry.root.as_deref(), Some("color"));
+    let derivative_of = &lexical_entry.derivative_of.as_ref().unwrap()[0];
+    assert_eq!(derivative_of.id, "color");
+    assert_eq!(derivative_of.language.as_deref(), Some("en-us"));
+    assert_eq!(derivative_of.domains.as_ref().unwrap()[0].id, "art");
+    assert_eq!(
+        lexical_entry.grammatical_features.as_ref().unwrap()[0].type_,
+        "Degree"
+    );
+    assert_eq!(
+        lexical_entry.notes.as_ref().unwrap()[0].type_,
+        "wordFormNote"
+    );
+    assert_eq!(
+        lexical_entry.pronunciations.as_ref().unwrap()[0]
+            .dialects
+            .as_ref()
+            .unwrap()[0],
+        "American English"
+    );
+    assert_eq!(
+        lexical_entry.variant_forms.as_ref().unwrap()[0].text,
+        "colourful"
+    );
+}
+
+#[test]
+fn error_response() {
+    let err =
+        block_on(client(Language::EnGb).entries::<RetrieveEntry>("xyzzy", &EntriesQuery::new()))
+            .unwrap_err();
+    assert!(err.is_not_found());
+    let details = err.details().unwrap();
+    assert_eq!(details.x_request_id.as_deref(), Some("fixture-xyzzy"));
+    assert!(details
+        .explanation
+        .as_ref()
+        .unwrap()
+        .error
+        .starts_with("No entry found"));
+}
+
+#[test]
+fn missing_fixture() {
+    let err =
+        block_on(client(Language::EnGb).entries::<RetrieveEntry>("lobster", &EntriesQuery::new()))
+            .unwrap_err();
+    assert!(matches!(err, Error::SurfError(_)));
+}
+
+#[test]
+fn record_then_replay() {
+    let dir = std::env::temp_dir().join(format!("oxdi-fixtures-{}", std::process::id()));
+    // records from the existing fixtures instead of the network
+    let recorder = FixtureClient::record(&dir, FixtureClient::replay(FIXTURES)).unwrap();
+    let recording = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .http_client(surf::Client::with_http_client(recorder))
+        .build()
+        .unwrap();
+    let recorded: RetrieveEntry =
+        block_on(recording.entries("crab", &EntriesQuery::new())).unwrap();
+    assert!(block_on(recording.entries::<RetrieveEntry>("xyzzy", &EntriesQuery::new())).is_err());
+
+    let replaying = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .fixtures(&dir, FixtureMode::Replay)
+        .build()
+        .unwrap();
+    let replayed: RetrieveEntry =
+        block_on(replaying.entries("crab", &EntriesQuery::new())).unwrap();
+    let err =
+        block_on(replaying.entries::<RetrieveEntry>("xyzzy", &EntriesQuery::new())).unwrap_err();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(format!("{:?}", recorded), format!("{:?}", replayed));
+    assert_eq!(
+        err.details().unwrap().x_request_id.as_deref(),
+        Some("fixture-xyzzy")
+    );
+}
+
+#[test]
+fn record_raw_bodies() {
+    let temp = |name: &str| {
+        let dir = std::env::temp_dir().join(format!("oxdi-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    };
+    let (src, dst) = (temp("raw-src"), temp("raw-dst"));
+    let bodies = [
+        ("html", "<html><body>Service Unavailable</body></html>"),
+        ("string", "\"just a string\""),
+        ("spaced", "{ \"results\" :  [ ]\n}"),
+    ];
+    for (word, body) in &bodies {
+        let fixture = serde_json::json!({
+            "request": {"method": "GET", "url": format!("api/v2/entries/en-gb/{}", word)},
+            "response": {"status": 200, "body": body}
+        });
+        let file = src.join(format!("api%2Fv2%2Fentries%2Fen-gb%2F{}.json", word));
+        std::fs::write(file, fixture.to_string()).unwrap();
+    }
+
+    let get = |http_client: FixtureClient, word: &str| {
+        let client = surf::Client::with_http_client(http_client);
+        let url = format!("http://localhost/api/v2/entries/en-gb/{}", word);
+        block_on(client.get(url).recv_string()).unwrap()
+    };
+    for (word, body) in &bodies {
+        let recorder = FixtureClient::record(&dst, FixtureClient::replay(&src)).unwrap();
+        assert_eq!(get(recorder, word), *body);
+        assert_eq!(get(FixtureClient::replay(&dst), word), *body);
+    }
+    std::fs::remove_dir_all(src).unwrap();
+    std::fs::remove_dir_all(dst).unwrap();
+}
+
+#[test]
+fn unsupported_query() {
+    let query = EntriesQuery::new().field(Field::Synonyms);
+    let err =
+        block_on(client(Language::EnGb).entries::<RetrieveEntry>("crab", &query)).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::InvalidQuery { ref api, ref param } if api == "entries" && param == "fields=synonyms"
+    ));
+}
+
+#[test]
+fn invalid_word_ids() {
+    let client = client(Language::EnGb);
+    let query = EntriesQuery::new();
+    for word_id in &["", "  ", ".", "..", "..."] {
+        let err = block_on(client.entries::<RetrieveEntry>(word_id, &query)).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::InvalidQuery { ref api, ref param } if api == "entries" && param == "word_id"
+            ),
+            "{:?}: {}",
+            word_id,
+            err
+        );
+    }
+    let err = block_on(client.lemmas::<serde_json::Value>("..", &query)).unwrap_err();
+    assert!(matches!(err, Error::InvalidQuery { ref api, .. } if api == "lemmas"));
+    // dots within a word are fine, there is just no fixture
+    let err = block_on(client.entries::<RetrieveEntry>("e.g.", &query)).unwrap_err();
+    assert!(matches!(err, Error::SurfError(_)));
+}
+
+#[test]
+fn unknown_fields() {
+    let entry: RetrieveEntry =
+        block_on(client(Language::EnGb).entries("drift", &EntriesQuery::new())).unwrap();
+    let metadata = entry.metadata.as_ref().unwrap();
+    assert_eq!(metadata.schema.as_deref(), Some("RetrieveEntry"));
+    let lexical_entry = &entry.results.as_ref().unwrap()[0].lexical_entries[0];
+    assert_eq!(lexical_entry.extra["frequency"], 42);
+    assert!(entry.extra.is_empty());
+}
+
+#[test]
+fn strict_mode() {
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .fixtures(FIXTURES, FixtureMode::Replay)
+        .strict(true)
+        .build()
+        .unwrap();
+    assert!(client.is_strict());
+    block_on(client.entries::<RetrieveEntry>("crab", &EntriesQuery::new())).unwrap();
+
+    let err = block_on(client.entries::<RetrieveEntry>("drift", &EntriesQuery::new())).unwrap_err();
+    match err {
+        Error::SchemaMismatch {
+            path, json_path, ..
+        } => {
+            assert_eq!(path, "entries/en-gb/drift");
+            assert_eq!(json_path, "results[0].lexicalEntries[0]");
+        }
+        other => panic!("unexpected error {:?}", other),
+    }
+}
+
+#[test]
+fn sense_traversal() {
+    let entry: RetrieveEntry =
+        block_on(client(Language::EnGb).entries("crab", &EntriesQuery::new())).unwrap();
+    let senses: Vec<String> = entry
+        .all_senses()
+        .map(|(context, sense)| {
+            format!(
+                "{} {} {} {}",
+                context.headword,
+                context.lexical_category.unwrap().id,
+                context.sense_number.unwrap(),
+                sense.id.as_deref().unwrap()
+            )
+        })
+        .collect();
+    assert_eq!(
+        senses,
+        [
+            "crab noun 1.1 m_en_gbus0233580.006",
+            "crab noun 1.1.a m_en_gbus0233580.010",
+            "crab noun 1.1.b m_en_gbus0233580.012",
+            "crab noun 1.2 m_en_gbus0233580.015",
+            "crab verb 1.1 m_en_gbus0233580.022",
+        ]
+    );
+
+    let (context, definition) = entry.all_definitions().nth(1).unwrap();
+    assert_eq!(definition, "the flesh of a crab as food");
+    assert!(context.sense_number.unwrap().is_subsense());
+    let examples: Vec<&str> = entry
+        .all_examples()
+        .map(|(_, example)| example.text.as_str())
+        .collect();
+    assert_eq!(
+        examples,
+        [
+            "fishermen in the area catch crabs and lobsters",
+            "dressed crab"
+        ]
+    );
+    let (context, pronunciation) = entry.all_pronunciations().next().unwrap();
+    assert_eq!(pronunciation.phonetic_spelling.as_deref(), Some("kræb"));
+    assert_eq!(context.lexical_category.unwrap().id, "noun");
+    assert!(context.sense_number.is_none());
+
+    // the numbers are the same when starting from the lexical entry
+    let verb = &entry.results.as_ref().unwrap()[0].lexical_entries[1];
+    let (context, _) = verb.all_senses().next().unwrap();
+    assert_eq!(context.sense_number.unwrap().levels(), [1, 1]);
+}
+
+#[test]
+fn custom_visitor() {
+    #[derive(Default)]
+    struct Counter {
+        headwords: usize,
+        lexical_entries: usize,
+        entries: usize,
+        domains: Vec<String>,
+    }
+    impl<'a> Visitor<'a> for Counter {
+        fn visit_headword(&mut self, _: &'a HeadwordEntry) {
+            self.headwords += 1;
+        }
+        fn visit_lexical_entry(&mut self, _: &Context<'a>, _: &'a LexicalEntry) {
+            self.lexical_entries += 1;
+        }
+        fn visit_entry(&mut self, _: &Context<'a>, _: &'a Entry) {
+            self.entries += 1;
+        }
+        fn visit_sense(&mut self, context: &Context<'a>, sense: &'a Sense) {
+            for domain in sense.domains.iter().flatten() {
+                let number = context.sense_number.as_ref().unwrap();
+                self.domains.push(format!("{} {}", number, domain.id));
+            }
+        }
+    }
+
+    let entry: RetrieveEntry =
+        block_on(client(Language::EnGb).entries("crab", &EntriesQuery::new())).unwrap();
+    let mut counter = Counter::default();
+    entry.walk(&mut counter);
+    assert_eq!(counter.headwords, 1);
+    assert_eq!(counter.lexical_entries, 2);
+    assert_eq!(counter.entries, 2);
+    assert_eq!(counter.domains, ["1.1.b astrology", "1.1 nautical"]);
+}
+
+#[test]
+fn simple_entry() {
+    let entry: RetrieveEntry =
+        block_on(client(Language::EnGb).entries("crab", &EntriesQuery::new())).unwrap();
+    let simple = SimpleEntry::from(&entry);
+    assert_eq!(simple.word, "crab");
+    let noun = &simple.lexical_entries[0];
+    assert_eq!(noun.part_of_speech, LexicalCategoryId::Noun);
+    assert_eq!(noun.ipa, ["kræb"]);
+    // subsenses are skipped, the senses without short definitions use the full ones
+    assert_eq!(
+        noun.definitions,
+        [
+            "crustacean with broad carapace, stalked eyes, and five pairs of legs",
+            "crab louse"
+        ]
+    );
+    assert_eq!(
+        noun.examples,
+        ["fishermen in the area catch crabs and lobsters"]
+    );
+    let verb = &simple.lexical_entries[1];
+    assert_eq!(verb.part_of_speech, LexicalCategoryId::Verb);
+    assert!(verb.ipa.is_empty());
+    assert_eq!(verb.definitions, ["move sideways or obliquely"]);
+
+    let colour: RetrieveEntry = block_on(
+        client(Language::EnGb).entries("colour", &EntriesQuery::new().strict_match(false)),
+    )
+    .unwrap();
+    let simple = SimpleEntry::with_examples(&colour, 0);
+    assert!(simple.lexical_entries.iter().all(|e| e.examples.is_empty()));
+    let json = serde_json::to_string(&simple).unwrap();
+    assert_eq!(serde_json::from_str::<SimpleEntry>(&json).unwrap(), simple);
+}
+
+#[test]
+fn phrase_word_ids() {
+    assert_eq!(normalize_word_id("Ice Cream"), "ice_cream");
+    assert_eq!(normalize_word_id("ice\tcream "), "ice_cream");
+    assert_eq!(normalize_word_id("Crab"), "Crab");
+    assert_eq!(normalize_word_id("crème"), "crème");
+    assert_eq!(normalize_word_id("  "), "");
+
+    // the case of a single word reaches the API, so strict match can use it
+    let cache = ResponseCache::new(10);
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .cache(cache.clone())
+        .offline(true)
+        .build()
+        .unwrap();
+    let query = EntriesQuery::new().strict_match(true);
+    cache.insert("entries/en-gb/Polish?strictMatch=true", "{}");
+    cache.insert("entries/en-gb/ice_cream?strictMatch=true", "{}");
+    cache.insert("entries/en-gb/cr%C3%A8me?strictMatch=true", "{}");
+    for word in ["Polish", "Ice Cream", "crème"] {
+        let response = block_on(client.entries::<serde_json::Value>(word, &query));
+        assert!(response.is_ok(), "{}", word);
+    }
+    let err = block_on(client.entries::<serde_json::Value>("polish", &query)).unwrap_err();
+    assert!(matches!(err, Error::OfflineCacheMiss { .. }));
+}
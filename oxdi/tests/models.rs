@@ -0,0 +1,344 @@
+//! Deserialize → serialize → deserialize round trips of the models
+use oxdi::models::*;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Checks that the serialized model deserializes to an equal model
+fn lossless<T>(original: Value) -> T
+where
+    T: DeserializeOwned + Serialize + PartialEq + Debug,
+{
+    let model: T = serde_json::from_value(original).unwrap();
+    let serialized = serde_json::to_value(&model).unwrap();
+    let again: T = serde_json::from_value(serialized.clone()).unwrap();
+    assert_eq!(again, model);
+    assert_eq!(serde_json::to_value(&again).unwrap(), serialized);
+    model
+}
+
+/// Checks that the model is lossless and serializes back
+/// to the same json, with the original camelCase names
+fn round_trip<T>(original: Value) -> T
+where
+    T: DeserializeOwned + Serialize + PartialEq + Debug,
+{
+    let model: T = lossless(original.clone());
+    assert_eq!(serde_json::to_value(&model).unwrap(), original);
+    model
+}
+
+#[test]
+fn entries_fixtures() {
+    let mut checked = 0;
+    for file in std::fs::read_dir(FIXTURES).unwrap() {
+        let text = std::fs::read_to_string(file.unwrap().path()).unwrap();
+        let fixture: Value = serde_json::from_str(&text).unwrap();
+        if fixture["response"]["status"] != 200 {
+            continue;
+        }
+        let entry: RetrieveEntry = round_trip(fixture["response"]["body"].clone());
+        assert_eq!(entry.clone(), entry);
+        checked += 1;
+    }
+    assert!(checked >= 3);
+}
+
+#[test]
+fn borrowed_entries() {
+    for file in std::fs::read_dir(FIXTURES).unwrap() {
+        let text = std::fs::read_to_string(file.unwrap().path()).unwrap();
+        let fixture: Value = serde_json::from_str(&text).unwrap();
+        if fixture["response"]["status"] != 200 {
+            continue;
+        }
+        let json = serde_json::to_vec(&fixture["response"]["body"]).unwrap();
+        let owned: RetrieveEntry = serde_json::from_slice(&json).unwrap();
+        let entry = borrowed::RetrieveEntry::from_slice(&json).unwrap();
+        assert_eq!(
+            serde_json::to_value(&entry).unwrap(),
+            serde_json::to_value(&owned).unwrap()
+        );
+        assert_eq!(RetrieveEntry::from(entry), owned);
+    }
+}
+
+#[test]
+fn borrowed_strings() {
+    let json = br#"{"results": [{
+        "id": "crab",
+        "language": "en-gb",
+        "lexicalEntries": [{
+            "entries": [{"senses": [{"definitions": ["a \"crab\""], "id": "s1"}]}],
+            "language": "en-gb",
+            "lexicalCategory": {"id": "noun", "text": "Noun"},
+            "text": "crab"
+        }],
+        "word": "crab"
+    }]}"#;
+    let entry = borrowed::RetrieveEntry::from_slice(json).unwrap();
+    let lexical_entry = &entry.results.as_ref().unwrap()[0].lexical_entries[0];
+    assert!(matches!(lexical_entry.text, Cow::Borrowed("crab")));
+    let sense = &lexical_entry.entries.as_ref().unwrap()[0]
+        .senses
+        .as_ref()
+        .unwrap()[0];
+    assert!(matches!(sense.id, Some(Cow::Borrowed("s1"))));
+    // escaped strings can't be borrowed
+    let definitions = sense.definitions.as_ref().unwrap();
+    assert!(matches!(&definitions[0], Cow::Owned(text) if text == "a \"crab\""));
+}
+
+#[test]
+fn unknown_fields_round_trip() {
+    let entry: RetrieveEntry = round_trip(json!({
+        "metadata": {"schema": "RetrieveEntry", "version": "2.1"},
+        "results": [{
+            "id": "crab",
+            "language": "en-gb",
+            "lexicalEntries": [{
+                "entries": [{"senses": [{"id": "s1", "frequency": {"band": 5}}]}],
+                "language": "en-gb",
+                "lexicalCategory": {"id": "noun", "text": "Noun", "parent": "word"},
+                "text": "crab"
+            }],
+            "word": "crab",
+            "sources": ["oed"]
+        }]
+    }));
+    assert_eq!(entry.metadata.as_ref().unwrap().extra["version"], "2.1");
+    let headword = &entry.results.as_ref().unwrap()[0];
+    assert_eq!(headword.extra["sources"], json!(["oed"]));
+    let lexical_entry = &headword.lexical_entries[0];
+    assert_eq!(lexical_entry.lexical_category.extra["parent"], "word");
+    let sense = &lexical_entry.entries.as_ref().unwrap()[0]
+        .senses
+        .as_ref()
+        .unwrap()[0];
+    assert_eq!(sense.extra["frequency"]["band"], 5);
+}
+
+#[test]
+fn labels() {
+    let labels: Labels = round_trip(json!({
+        "metadata": {"provider": "Oxford University Press"},
+        "results": [
+            {"id": "past", "text": "Past", "type": "Tense"},
+            {"id": "plural", "text": "Plural", "type": "Number", "abbreviation": "pl."},
+        ]
+    }));
+    assert_eq!(labels.types["plural"], "Number");
+    assert_eq!(labels.extras["plural"]["abbreviation"], "pl.");
+    assert!(!labels.extras.contains_key("past"));
+
+    // labels without types
+    round_trip::<Labels>(json!({"results": [{"id": "art", "text": "Art"}]}));
+}
+
+#[test]
+fn ids_compare_by_str() {
+    use std::collections::HashSet;
+    let other = LexicalCategoryId::Other("noun".to_owned());
+    assert_eq!(other, LexicalCategoryId::Noun);
+    assert_eq!(LexicalCategoryId::Noun, other);
+    assert_ne!(other, LexicalCategoryId::Verb);
+    let set: HashSet<_> = vec![LexicalCategoryId::Noun, other].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let mut ids = vec![
+        RegisterId::Other("zzz".to_owned()),
+        RegisterId::VulgarSlang,
+        RegisterId::Other("aaa".to_owned()),
+    ];
+    ids.sort();
+    assert_eq!(ids, ["aaa", "vulgar_slang", "zzz"]);
+}
+
+#[test]
+fn lemmas() {
+    let lemmatron: Lemmatron = round_trip(json!({
+        "results": [{
+            "id": "crabs",
+            "language": "en-gb",
+            "lexicalEntries": [{
+                "grammaticalFeatures": [{"id": "plural", "text": "Plural", "type": "Number"}],
+                "inflectionOf": [{"id": "crab", "text": "crab"}],
+                "language": "en-gb",
+                "lexicalCategory": {"id": "noun", "text": "Noun"},
+                "text": "crabs"
+            }],
+            "word": "crabs"
+        }]
+    }));
+    assert_eq!(lemmatron.lemmas(), ["crab"]);
+}
+
+#[test]
+fn translations() {
+    round_trip::<RetrieveTranslation>(json!({
+        "results": [{
+            "id": "crab",
+            "language": "en",
+            "lexicalEntries": [{
+                "entries": [{
+                    "senses": [{
+                        "datasetCrossLinks": [{"entry_id": "crab", "sense_id": "m_en_gbus0236170.005"}],
+                        "examples": [{
+                            "text": "crab claws",
+                            "translations": [{"language": "es", "text": "pinzas de cangrejo"}]
+                        }],
+                        "translations": [{
+                            "language": "es",
+                            "text": "cangrejo",
+                            "grammaticalFeatures": [{"id": "masculine", "text": "Masculine", "type": "Gender"}],
+                            "toneGroups": [{"tones": [{"type": "tone", "value": "high"}]}]
+                        }]
+                    }]
+                }],
+                "language": "en",
+                "lexicalCategory": {"id": "noun", "text": "Noun"},
+                "text": "crab"
+            }],
+            "type": "headword",
+            "word": "crab"
+        }]
+    }));
+}
+
+#[test]
+fn thesaurus_sentences_and_search() {
+    round_trip::<RetrieveThesaurus>(json!({
+        "results": [{
+            "id": "big",
+            "language": "en",
+            "lexicalEntries": [{
+                "entries": [{
+                    "senses": [{
+                        "id": "t_en_gb0001",
+                        "synonyms": [{"language": "en", "text": "large"}],
+                        "antonyms": [{"language": "en", "text": "small"}],
+                        "registers": [{"id": "informal", "text": "Informal"}]
+                    }]
+                }],
+                "language": "en",
+                "lexicalCategory": {"id": "adjective", "text": "Adjective"},
+                "text": "big"
+            }],
+            "word": "big"
+        }]
+    }));
+    round_trip::<SentencesResults>(json!({
+        "results": [{
+            "id": "crab",
+            "language": "en",
+            "lexicalEntries": [{
+                "language": "en",
+                "lexicalCategory": {"id": "noun", "text": "Noun"},
+                "sentences": [{
+                    "regions": [{"id": "british", "text": "British"}],
+                    "senseIds": ["m_en_gbus0236170.005"],
+                    "text": "The crab scuttled away."
+                }],
+                "text": "crab"
+            }],
+            "word": "crab"
+        }]
+    }));
+    round_trip::<SearchResults>(json!({
+        "results": [{
+            "id": "crab",
+            "label": "crab",
+            "matchString": "crab",
+            "matchType": "headword",
+            "score": 1.5,
+            "word": "crab"
+        }]
+    }));
+}
+
+#[test]
+fn paradigm() {
+    let feature = |type_: &str, id: &str| json!({"id": id, "text": id, "type": type_});
+    let (singular, plural) = (feature("Number", "singular"), feature("Number", "plural"));
+    let (nominative, genitive) = (feature("Case", "nominative"), feature("Case", "genitive"));
+    let lexical_entry: inflections::InflectionsLexicalEntry = serde_json::from_value(json!({
+        "inflections": [
+            {"grammaticalFeatures": [singular, nominative], "inflectedForm": "Haus"},
+            {"grammaticalFeatures": [singular, genitive], "inflectedForm": "Hauses"},
+            {"grammaticalFeatures": [singular, genitive], "inflectedForm": "Haus'"},
+            {"grammaticalFeatures": [singular, genitive], "inflectedForm": "Hauses"},
+            {"grammaticalFeatures": [plural], "inflectedForm": "Häuser"}
+        ],
+        "language": "de",
+        "lexicalCategory": {"id": "noun", "text": "Noun"},
+        "text": "Haus"
+    }))
+    .unwrap();
+    let paradigm = lexical_entry.paradigm();
+    assert_eq!(paradigm.axes, ["Case", "Number"]);
+    assert_eq!(paradigm.cells.len(), 3);
+    // duplicated forms are kept once, in the order of the response
+    assert_eq!(
+        paradigm.get(&["genitive", "singular"]),
+        Some(&["Hauses", "Haus'"][..])
+    );
+    // the form without a case
+    assert_eq!(paradigm.get(&["", "plural"]), Some(&["Häuser"][..]));
+    assert_eq!(paradigm.get(&["dative", "plural"]), None);
+    assert!(paradigm
+        .to_string()
+        .starts_with("Case       | Number   | Forms\n"));
+
+    // columns are aligned by characters, not bytes
+    let lexical_entry: inflections::InflectionsLexicalEntry = serde_json::from_value(json!({
+        "inflections": [
+            {"grammaticalFeatures": [feature("Cas", "génitif")], "inflectedForm": "naïf"},
+            {"grammaticalFeatures": [feature("Cas", "datif")], "inflectedForm": "naïve"}
+        ],
+        "language": "fr",
+        "lexicalCategory": {"id": "adjective", "text": "Adjective"},
+        "text": "naïf"
+    }))
+    .unwrap();
+    assert_eq!(
+        lexical_entry.paradigm().to_string(),
+        "Cas     | Forms\ndatif   | naïve\ngénitif | naïf\n"
+    );
+}
+
+#[test]
+fn inflections_and_languages() {
+    let inflections: RetrieveInflections = round_trip(json!({
+        "results": [{
+            "id": "crab",
+            "language": "en-gb",
+            "lexicalEntries": [{
+                "inflections": [{
+                    "grammaticalFeatures": [{"id": "plural", "text": "Plural", "type": "Number"}],
+                    "inflectedForm": "crabs"
+                }],
+                "language": "en-gb",
+                "lexicalCategory": {"id": "noun", "text": "Noun"},
+                "text": "crab"
+            }],
+            "word": "crab"
+        }]
+    }));
+    let lexical_entry = &inflections.results.as_ref().unwrap()[0].lexical_entries[0];
+    let paradigm = lexical_entry.paradigm();
+    assert_eq!(paradigm.axes, ["Number"]);
+    assert_eq!(paradigm.get(&["plural"]), Some(&["crabs"][..]));
+
+    round_trip::<RetrieveLanguages>(json!({
+        "results": [{
+            "source": "Oxford Spanish",
+            "sourceLanguage": {"id": "en", "language": "English"},
+            "targetLanguage": {"id": "es", "language": "Spanish"},
+            "type": "bilingual"
+        }]
+    }));
+    round_trip::<EndpointNames>(json!({"results": {"entries": ["definitions", "examples"]}}));
+}
@@ -0,0 +1,102 @@
+//! Token buckets of the rate limiter
+use futures::executor::block_on;
+use oxdi::apis::EntriesQuery;
+use oxdi::rate_limit::Budget;
+use oxdi::{ClientBuilder, Language, RateLimiter};
+use std::time::{Duration, Instant};
+
+#[test]
+fn remaining() {
+    assert_eq!(
+        RateLimiter::new().remaining(),
+        Budget {
+            per_second: None,
+            per_month: None
+        }
+    );
+    let limiter = RateLimiter::new().per_second(5).per_month(100);
+    assert_eq!(
+        limiter.remaining(),
+        Budget {
+            per_second: Some(5),
+            per_month: Some(100)
+        }
+    );
+    for _ in 0..3 {
+        block_on(limiter.acquire());
+    }
+    let budget = limiter.remaining();
+    assert_eq!(budget.per_second, Some(2));
+    assert_eq!(budget.per_month, Some(97));
+}
+
+#[test]
+fn refill() {
+    let limiter = RateLimiter::new().per_second(10);
+    let start = Instant::now();
+    for _ in 0..10 {
+        block_on(limiter.acquire());
+    }
+    // the burst is not delayed
+    assert!(start.elapsed() < Duration::from_millis(500));
+    assert_eq!(limiter.remaining().per_second, Some(0));
+
+    // the next token is refilled in about 100ms
+    let start = Instant::now();
+    block_on(limiter.acquire());
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    std::thread::sleep(Duration::from_millis(500));
+    let remaining = limiter.remaining().per_second.unwrap();
+    assert!((4..=10).contains(&remaining), "{}", remaining);
+    // never above the capacity
+    std::thread::sleep(Duration::from_millis(1100));
+    assert_eq!(limiter.remaining().per_second, Some(10));
+}
+
+#[test]
+fn shared_by_clones() {
+    let limiter = RateLimiter::new().per_second(100).per_month(10);
+    // nothing listens there, the requests fail after taking the budget
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .base_url("http://127.0.0.1:1/")
+        .rate_limiter(limiter.clone())
+        .build()
+        .unwrap();
+    let clone = client.clone();
+    let query = EntriesQuery::new();
+    assert!(block_on(client.entries::<serde_json::Value>("crab", &query)).is_err());
+    assert!(block_on(clone.entries::<serde_json::Value>("crab", &query)).is_err());
+    block_on(clone.rate_limiter().unwrap().acquire());
+
+    assert_eq!(limiter.remaining().per_month, Some(7));
+    assert_eq!(
+        client.rate_limiter().unwrap().remaining().per_month,
+        Some(7)
+    );
+    assert_eq!(clone.rate_limiter().unwrap().remaining().per_month, Some(7));
+}
+
+#[test]
+fn setters_return_new_limiter() {
+    let limiter = RateLimiter::new().per_month(10);
+    let throttled = limiter.clone().per_second(1);
+    for _ in 0..3 {
+        block_on(limiter.acquire());
+    }
+    // the earlier clone is not throttled and doesn't share the budget
+    assert_eq!(
+        limiter.remaining(),
+        Budget {
+            per_second: None,
+            per_month: Some(7)
+        }
+    );
+    assert_eq!(
+        throttled.remaining(),
+        Budget {
+            per_second: Some(1),
+            per_month: Some(10)
+        }
+    );
+}
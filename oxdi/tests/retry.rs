@@ -0,0 +1,149 @@
+//! Backoff of the retry policy and parsing of `Retry-After`
+use oxdi::retry::parse_retry_after;
+use oxdi::{ApiErrorDetails, Error, RetryPolicy};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use surf::StatusCode;
+
+fn policy(jitter: bool) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 10,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_secs(1),
+        jitter,
+        respect_retry_after: true,
+    }
+}
+
+#[test]
+fn backoff_growth() {
+    let policy = policy(false);
+    let delays: Vec<u128> = (1..=6)
+        .map(|attempt| policy.backoff(attempt, None).as_millis())
+        .collect();
+    assert_eq!(delays, [100, 200, 400, 800, 1000, 1000]);
+    // no overflow for the large attempts
+    assert_eq!(policy.backoff(u32::MAX, None), policy.max_backoff);
+}
+
+#[test]
+fn jitter_bounds() {
+    let policy = policy(true);
+    for attempt in 1..=6 {
+        let full = self::policy(false).backoff(attempt, None);
+        for _ in 0..100 {
+            let backoff = policy.backoff(attempt, None);
+            assert!(backoff >= full / 2 && backoff <= full, "{:?}", backoff);
+        }
+    }
+}
+
+#[test]
+fn retry_after() {
+    let mut policy = policy(true);
+    let retry_after = Some(Duration::from_millis(300));
+    assert_eq!(policy.backoff(1, retry_after), Duration::from_millis(300));
+    // capped by the max backoff
+    let day = Some(Duration::from_secs(86_400));
+    assert_eq!(policy.backoff(1, day), policy.max_backoff);
+
+    policy.respect_retry_after = false;
+    policy.jitter = false;
+    assert_eq!(policy.backoff(1, day), policy.initial_backoff);
+}
+
+#[test]
+fn parse_delta_seconds() {
+    let now = SystemTime::now();
+    assert_eq!(
+        parse_retry_after("120", now),
+        Some(Duration::from_secs(120))
+    );
+    assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+    assert_eq!(parse_retry_after("-1", now), None);
+    assert_eq!(parse_retry_after("soon", now), None);
+}
+
+#[test]
+fn parse_http_date() {
+    // Sun, 06 Nov 1994 08:49:37 GMT
+    let date = UNIX_EPOCH + Duration::from_secs(784_111_777);
+    let value = "Sun, 06 Nov 1994 08:49:37 GMT";
+    assert_eq!(parse_retry_after(value, date), Some(Duration::ZERO));
+    let earlier = date - Duration::from_secs(90);
+    assert_eq!(
+        parse_retry_after(value, earlier),
+        Some(Duration::from_secs(90))
+    );
+    // in the past
+    let later = date + Duration::from_secs(90);
+    assert_eq!(parse_retry_after(value, later), Some(Duration::ZERO));
+    // leap day
+    let leap_day = UNIX_EPOCH + Duration::from_secs(951_782_400);
+    assert_eq!(
+        parse_retry_after("Tue, 29 Feb 2000 00:00:00 GMT", leap_day),
+        Some(Duration::ZERO)
+    );
+
+    for invalid in [
+        "Sun, 06 Nov 1994 08:49:37",
+        "Sun, 06 Foo 1994 08:49:37 GMT",
+        "Sun, 06 Nov 1994 08:49 GMT",
+        "Sunday, 06-Nov-94 08:49:37 GMT",
+        "Sun, 06 Nov 1994 25:00:00 GMT",
+    ] {
+        assert_eq!(parse_retry_after(invalid, date), None, "{}", invalid);
+    }
+}
+
+#[test]
+fn retryable_statuses() {
+    let policy = RetryPolicy::default();
+    let details = || ApiErrorDetails {
+        path: "entries/en-gb/crab".to_owned(),
+        x_request_id: None,
+        explanation: None,
+    };
+    for status in [
+        StatusCode::TooManyRequests,
+        StatusCode::InternalServerError,
+        StatusCode::BadGateway,
+        StatusCode::ServiceUnavailable,
+        StatusCode::GatewayTimeout,
+    ] {
+        assert!(policy.is_retryable(status));
+    }
+    for status in [
+        StatusCode::BadRequest,
+        StatusCode::NotFound,
+        StatusCode::NotImplemented,
+    ] {
+        assert!(!policy.is_retryable(status));
+    }
+    // errors agree with the policy
+    for status in [StatusCode::BadGateway, StatusCode::NotImplemented] {
+        let error = Error::ServerError {
+            status,
+            details: details(),
+        };
+        assert_eq!(error.is_retryable(), policy.is_retryable(status));
+    }
+    assert!(Error::RateLimited {
+        retry_after: None,
+        details: details(),
+    }
+    .is_retryable());
+}
+
+#[test]
+fn builder() {
+    let policy = RetryPolicy::new()
+        .max_attempts(10)
+        .initial_backoff(Duration::from_millis(100))
+        .max_backoff(Duration::from_secs(1))
+        .jitter(true)
+        .respect_retry_after(true);
+    assert_eq!(policy, self::policy(true));
+    assert_eq!(RetryPolicy::new(), RetryPolicy::default());
+    // at least the first attempt is made
+    assert_eq!(RetryPolicy::new().max_attempts(0).max_attempts, 1);
+}
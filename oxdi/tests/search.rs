@@ -0,0 +1,76 @@
+//! Query strings and pagination of the search APIs
+use futures::executor::block_on;
+use futures::StreamExt;
+use oxdi::apis::search::Scope;
+use oxdi::models::{RegionId, SearchResults};
+use oxdi::{ClientBuilder, Language, ResponseCache, SearchQuery};
+use serde_json::{json, Value};
+
+fn page(words: &[&str]) -> String {
+    let results: Vec<Value> = words
+        .iter()
+        .map(|word| {
+            json!({
+                "id": word,
+                "label": word,
+                "matchString": "cra",
+                "matchType": "headword",
+                "word": word
+            })
+        })
+        .collect();
+    json!({ "results": results }).to_string()
+}
+
+#[test]
+fn query_string() {
+    let query = SearchQuery::new()
+        .region(RegionId::British)
+        .region("british")
+        .region(RegionId::Us)
+        .offset(5)
+        .prefix(false)
+        .offset(10);
+    assert_eq!(
+        query.to_string(),
+        "prefix=false&regions=british%2Cus&offset=10"
+    );
+    assert_eq!(SearchQuery::new().to_string(), "");
+}
+
+#[test]
+fn pages() {
+    let cache = ResponseCache::new(10);
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .cache(cache.clone())
+        .offline(true)
+        .build()
+        .unwrap();
+    cache.insert(
+        "search/en-gb?q=cra&prefix=true&limit=2&offset=0",
+        page(&["crab", "crack"]),
+    );
+    cache.insert(
+        "search/en-gb?q=cra&prefix=true&limit=2&offset=2",
+        page(&["craft"]),
+    );
+    // the limit and offset of the params are replaced, not repeated
+    let params = SearchQuery::new().prefix(true).limit(100).offset(7);
+    let pages: Vec<SearchResults> = block_on(
+        client
+            .search_pages(Scope::Dictionary, "cra", 2, &params)
+            .map(Result::unwrap)
+            .collect(),
+    );
+    let words: Vec<&str> = pages
+        .iter()
+        .flat_map(|page| page.results.iter().flatten())
+        .map(|result| result.word.as_str())
+        .collect();
+    assert_eq!(words, ["crab", "crack", "craft"]);
+
+    // the query text is percent-encoded
+    cache.insert("search/en-gb?q=ice%20cream", page(&["ice_cream"]));
+    let results: SearchResults = block_on(client.search("ice cream", &SearchQuery::new())).unwrap();
+    assert_eq!(results.results.unwrap()[0].id, "ice_cream");
+}
@@ -1,6 +1,8 @@
 use anyhow::{Context, Result};
 use argh::FromArgs;
-use oxdi::apis::entries::{Field, Param};
+use oxdi::apis::{EntriesQuery, Field};
+use oxdi::models::{LexicalCategoryId, SimpleEntry, Walk};
+use std::path::PathBuf;
 //use tracing::{info, Level};
 //use tracing_subscriber::{EnvFilter, FmtSubscriber};
 
@@ -8,7 +10,7 @@ use oxdi::apis::entries::{Field, Param};
 /// Search in Oxford Dictionary from CLI!
 struct Args {
     #[argh(option, short = 'w', from_str_fn(parse_word))]
-    /// case sensetive word to query
+    /// word or phrase to query
     word: String,
 
     #[argh(switch)]
@@ -27,6 +29,23 @@ struct Args {
     #[argh(option, short = 't', from_str_fn(parse_lang))]
     /// target language
     target_language: Option<oxdi::Language>,
+
+    #[argh(switch, short = 'i')]
+    /// print inflection paradigms
+    inflections: bool,
+
+    #[argh(switch)]
+    /// print the entry as JSON
+    json: bool,
+
+    #[argh(switch)]
+    /// serve responses only from the cache, without network
+    offline: bool,
+
+    #[argh(option)]
+    /// cache directory, by default `$OXDI_CACHE_DIR`, responses are not
+    /// cached if neither is set
+    cache_dir: Option<PathBuf>,
     // #[argh(option, from_str_fn(parse_vec_string))]
     // /// filter by grammatical features
     // grammatical_features: Option<Vec<String>>,
@@ -55,12 +74,22 @@ fn parse_lang(value: &str) -> Result<oxdi::Language, String> {
         Err("invalid lang code, see documentation for valid language codes".to_owned())
     }
 }
+/// `$OXDI_CACHE_DIR` if set, the disk cache is disabled otherwise
+fn default_cache_dir() -> Option<PathBuf> {
+    std::env::var_os("OXDI_CACHE_DIR")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
 fn parse_word(value: &str) -> Result<String, String> {
+    let value = value.trim();
     if value.is_empty() {
         Err("must be non empty".to_owned())
-    // if at least one char is non alphabetic -> error
-    } else if value.chars().all(|x| x.is_alphabetic()) == false {
-        Err("must contain only alphabetic characters".to_owned())
+    // phrases like "ice cream", "well-being" or "o'clock" are allowed
+    } else if !value
+        .chars()
+        .all(|x| x.is_alphabetic() || x == ' ' || x == '-' || x == '\'' || x == '’')
+    {
+        Err("must contain only letters, spaces, hyphens and apostrophes".to_owned())
     } else {
         Ok(value.to_owned())
     }
@@ -80,17 +109,35 @@ async fn run() -> Result<()> {
     }
     let word = args.word.as_str();
 
+    // keys are not needed offline
     #[cfg(not(feature = "embed-keys"))]
-    let (id, key) = (
-        std::env::var("OXFORD_API_ID").unwrap(),
-        std::env::var("OXFORD_API_KEY").unwrap(),
-    );
+    let (id, key) = if args.offline {
+        (
+            std::env::var("OXFORD_API_ID").unwrap_or_default(),
+            std::env::var("OXFORD_API_KEY").unwrap_or_default(),
+        )
+    } else {
+        (
+            std::env::var("OXFORD_API_ID").context("OXFORD_API_ID is not set")?,
+            std::env::var("OXFORD_API_KEY").context("OXFORD_API_KEY is not set")?,
+        )
+    };
     #[cfg(feature = "embed-keys")]
     let (id, key) = (
         dotenv_codegen::dotenv!("OXFORD_API_ID").to_owned(),
         dotenv_codegen::dotenv!("OXFORD_API_KEY").to_owned(),
     );
-    let client = oxdi::Client::new(id, key, oxdi::Language::EnGb);
+    let mut builder = oxdi::Client::builder(id, key, args.language).offline(args.offline);
+    match args.cache_dir.or_else(default_cache_dir) {
+        Some(dir) => {
+            let disk_cache = oxdi::DiskCache::new(&dir)
+                .with_context(|| format!("failed to open cache directory {}", dir.display()))?;
+            builder = builder.disk_cache(disk_cache);
+        }
+        None if args.offline => anyhow::bail!("offline mode requires --cache-dir"),
+        None => {}
+    }
+    let client = builder.build()?;
     // let subscriber = FmtSubscriber::builder()
     //     .pretty()
     //     .with_env_filter(EnvFilter::from_default_env())
@@ -99,68 +146,122 @@ async fn run() -> Result<()> {
 
     // tracing::subscriber::set_global_default(subscriber).expect("setting default subscriber failed");
 
-    let resp: oxdi::models::RetrieveEntry = client
-        .entries(
+    if let Some(target) = args.target_language {
+        return print_translations(&client, word, target).await;
+    }
+    if args.inflections {
+        return print_inflections(&client, word).await;
+    }
+
+    let lemmatized = client
+        .entries_lemmatized(
             word,
-            &[Param::Fields(&[
+            &EntriesQuery::new().fields(&[
                 Field::Pronunciations,
                 Field::Examples,
                 Field::Definitions,
-            ])],
+            ]),
         )
         .await
         .context("request to HTTP API failed")?;
+    if let Some(lemma) = lemmatized.lemma.as_ref() {
+        bunt::println!("{$dimmed}`{}` is a form of `{}`{/$}", word, lemma);
+    }
+    let resp: oxdi::models::RetrieveEntry = lemmatized.value;
+    if args.json {
+        let simple = SimpleEntry::from(&resp);
+        println!("{}", serde_json::to_string_pretty(&simple)?);
+        return Ok(());
+    }
 
-    for headword_entry in resp.results.context("no results returned")? {
+    for headword_entry in resp.results.as_ref().context("no results returned")? {
         // let word_type = headword_entry.type_.unwrap();
-        for lexical_entry in headword_entry.lexical_entries {
-            let text = lexical_entry.text;
-            let category = lexical_entry.lexical_category.text;
-            let prety_print = match lexical_entry.lexical_category.id.as_str() {
-                "noun" => {
+        for lexical_entry in &headword_entry.lexical_entries {
+            let text = &lexical_entry.text;
+            let category = &lexical_entry.lexical_category.text;
+            let prety_print = match lexical_entry.lexical_category.id {
+                LexicalCategoryId::Noun => {
                     |text, category| bunt::println!("{[underline]}, {[cyan]}", text, category)
                 }
-                "verb" => {
+                LexicalCategoryId::Verb => {
                     |text, category| bunt::println!("{[underline]}, {[magenta]}", text, category)
                 }
                 _ => |text, category| bunt::println!("{[underline]}, {}", text, category),
                 // other => todo!("{}", other),
             };
-            prety_print(&text, &category);
-            //
+            prety_print(text, category);
             println!("Pronunciations:");
-            // println!("\t{}:", "Pronunciations");
+            for (_, pron) in lexical_entry.all_pronunciations() {
+                if let Some(spelling) = pron.phonetic_spelling.as_ref() {
+                    let dialects = pron.dialects.as_deref().unwrap_or_default();
+                    bunt::println!("\t/{[blue]}/, {}", spelling, dialects.join(", "));
+                }
+            }
+            let definitions = lexical_entry.all_definitions();
+            if definitions.len() != 0 {
+                bunt::println!("\t{$green}Definitions:{/$}");
+            }
+            for (context, defi) in definitions {
+                println!("\t{}) {}", context.sense_number.unwrap(), defi);
+            }
+            let examples = lexical_entry.all_examples();
+            if examples.len() != 0 {
+                bunt::println!("\t{$bold}Examples:{/$}");
+            }
+            for (context, example) in examples {
+                println!("\t{}) {}", context.sense_number.unwrap(), example.text);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn print_translations(
+    client: &oxdi::Client,
+    word: &str,
+    target: oxdi::Language,
+) -> Result<()> {
+    let resp: oxdi::models::RetrieveTranslation = client
+        .translations(
+            word,
+            target,
+            &EntriesQuery::new().fields(&[Field::Translations, Field::Examples]),
+        )
+        .await
+        .context("request to HTTP API failed")?;
+
+    for headword_entry in resp.results.context("no results returned")? {
+        for lexical_entry in headword_entry.lexical_entries {
+            bunt::println!(
+                "{[underline]}, {[cyan]}",
+                lexical_entry.text,
+                lexical_entry.lexical_category.text
+            );
             for entry in lexical_entry
                 .entries
                 .context("no entries in lexical entry")?
             {
-                for pron in entry.pronunciations.context("no pronunciations")? {
-                    bunt::println!(
-                        "\t/{[blue]}/, {}",
-                        pron.phonetic_spelling.context("no phonetic spelling")?,
-                        pron.dialects.context("no dialects")?.join(", ")
-                    );
-                }
-                if let Some(senses) = entry.senses.as_ref() {
-                    bunt::println!("\t{$green}Definitions:{/$}");
-                    for (i, sense) in senses.iter().enumerate() {
-                        for (i_defi, defi) in sense
-                            .definitions
-                            .as_ref()
-                            .context("no definitions")?
+                let senses = match entry.senses {
+                    Some(senses) => senses,
+                    None => continue,
+                };
+                bunt::println!("\t{$green}Translations:{/$}");
+                for (i, sense) in senses.iter().enumerate() {
+                    if let Some(translations) = sense.translations.as_ref() {
+                        let texts = translations
                             .iter()
-                            .enumerate()
-                        {
-                            println!("\t{}.{}) {}", i + 1, i_defi + 1, defi);
-                        }
+                            .map(|t| t.text.as_str())
+                            .collect::<Vec<&str>>();
+                        println!("\t{}) {}", i + 1, texts.join(", "));
                     }
-                    bunt::println!("\t{$bold}Examples:{/$}");
-                    for (i, sense) in senses.iter().enumerate() {
-                        if let Some(examples) = sense.examples.as_ref() {
-                            for (i_example, example) in examples.iter().enumerate() {
-                                println!("\t{}.{}) {}", i + 1, i_example + 1, example.text);
-                            }
-                        }
+                    for example in sense.examples.iter().flatten() {
+                        let translated = example
+                            .translations
+                            .iter()
+                            .flatten()
+                            .map(|t| t.text.as_str())
+                            .collect::<Vec<&str>>();
+                        bunt::println!("\t   {[dimmed]} — {}", example.text, translated.join("; "));
                     }
                 }
             }
@@ -168,3 +269,22 @@ async fn run() -> Result<()> {
     }
     Ok(())
 }
+
+async fn print_inflections(client: &oxdi::Client, word: &str) -> Result<()> {
+    let resp: oxdi::models::RetrieveInflections = client
+        .inflections(word, &EntriesQuery::new())
+        .await
+        .context("request to HTTP API failed")?;
+
+    for headword in resp.results.context("no results returned")? {
+        for lexical_entry in headword.lexical_entries {
+            bunt::println!(
+                "{[underline]}, {[cyan]}",
+                lexical_entry.text,
+                lexical_entry.lexical_category.text
+            );
+            println!("{}", lexical_entry.paradigm());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,390 @@
+//! Local mock server of the Oxford Dictionaries API v2, for testing
+//! code built on `oxdi` without API keys.
+//!
+//! Responses are served from a fixture directory:
+//! - `entries/<lang>/<word>.json`
+//! - `lemmas/<lang>/<word>.json`
+//! - `words/<lang>/<q>.json`, named after the decoded `q` param
+//! - `sentences/<lang>/<word>.json`
+//! - `translations/<source>/<target>/<word>.json`
+//! - `grammaticalFeatures/<lang>.json`
+//!
+//! A fixture named `<word>.<status>.json` (e.g. `crab.500.json`) is
+//! served with that status instead of `200 OK`. Words without a fixture
+//! get `404 Not Found` like from the real API. Every response carries
+//! an `X-Request-Id` header. Other query params are ignored, but the
+//! received requests can be inspected with [`MockHandle::requests`].
+//!
+//! Transient failures are simulated with [`MockServer::fail`] and
+//! [`MockServer::retry_after`].
+//! ## Example
+/*!```no_run
+use oxdi::{ClientBuilder, Language};
+use oxdi_mock::MockServer;
+# async_std::task::block_on(async {
+let mock = MockServer::new("fixtures").spawn().await.expect("handle errors");
+let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+    .base_url(&mock.base_url())
+    .build()
+    .expect("handle errors");
+# })
+```*/
+use async_std::task::JoinHandle;
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::net::{SocketAddr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tide::http::mime;
+use tide::{Request, Response, StatusCode};
+use tracing::{trace, warn};
+
+/// Path prefix of the API, as in [`oxdi::API_BASE_URL`]
+pub const API_PREFIX: &str = "/api/v2";
+
+/// Configuration of the mock server
+#[derive(Debug, Clone)]
+pub struct MockServer {
+    fixtures: PathBuf,
+    credentials: Option<(String, String)>,
+    retry_after: Option<u64>,
+    failures: Vec<Failure>,
+}
+
+/// Running mock server, stopped when dropped
+#[derive(Debug)]
+pub struct MockHandle {
+    addr: SocketAddr,
+    state: State,
+    task: Option<JoinHandle<io::Result<()>>>,
+}
+
+/// Request received by the mock server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    /// Path relative to the [`API_PREFIX`](API_PREFIX), e.g. `entries/en-gb/crab`
+    pub path: String,
+    /// Query string as sent, without the leading `?`
+    pub query: Option<String>,
+    /// Headers with lowercase names
+    pub headers: Vec<(String, String)>,
+}
+
+impl MockRequest {
+    /// Last value of the header `name`
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .rev()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Status served instead of the fixture for the next `times` requests of the `path`
+#[derive(Debug, Clone)]
+struct Failure {
+    path: String,
+    status: StatusCode,
+    times: u32,
+}
+
+#[derive(Debug)]
+struct Inner {
+    fixtures: PathBuf,
+    credentials: Option<(String, String)>,
+    retry_after: Option<u64>,
+    failures: Mutex<Vec<Failure>>,
+    next_request_id: AtomicU64,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+#[derive(Debug, Clone)]
+struct State(Arc<Inner>);
+
+impl MockServer {
+    /// Serves the fixtures from the `fixtures` directory,
+    /// any credentials are accepted
+    pub fn new(fixtures: impl Into<PathBuf>) -> Self {
+        Self {
+            fixtures: fixtures.into(),
+            credentials: None,
+            retry_after: None,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Requests without these `app_id` and `app_key` headers
+    /// are rejected with `403 Forbidden`
+    pub fn credentials(mut self, app_id: &str, app_key: &str) -> Self {
+        self.credentials = Some((app_id.to_owned(), app_key.to_owned()));
+        self
+    }
+
+    /// Responses with `429 Too Many Requests` or `503 Service Unavailable`
+    /// carry the `Retry-After` header of `seconds`
+    pub fn retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
+
+    /// The first `times` requests of the `path` (relative to the [`API_PREFIX`](API_PREFIX),
+    /// e.g. `entries/en-gb/crab`) fail with the `status`, the next ones are served
+    /// as usual. Can be called multiple times, the failures of a path are consumed in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `status` is not a valid status code
+    pub fn fail(mut self, path: &str, status: u16, times: u32) -> Self {
+        let status = StatusCode::try_from(status).expect("invalid status code");
+        self.failures.push(Failure {
+            path: path.trim_start_matches('/').to_owned(),
+            status,
+            times,
+        });
+        self
+    }
+
+    /// Tide application of the server, e.g. to nest it into another one
+    pub fn into_app(self) -> tide::Server<impl Clone + Send + Sync + 'static> {
+        app(self.into_state())
+    }
+
+    fn into_state(self) -> State {
+        State(Arc::new(Inner {
+            fixtures: self.fixtures,
+            credentials: self.credentials,
+            retry_after: self.retry_after,
+            failures: Mutex::new(self.failures),
+            next_request_id: AtomicU64::new(1),
+            requests: Mutex::new(Vec::new()),
+        }))
+    }
+
+    /// Serves on the `addr` until an error occurs
+    pub async fn listen(self, addr: &str) -> io::Result<()> {
+        self.into_app().listen(addr.to_owned()).await
+    }
+
+    /// Serves on a free port of `127.0.0.1` in the background
+    pub async fn spawn(self) -> io::Result<MockHandle> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let state = self.into_state();
+        let task = async_std::task::spawn(app(state.clone()).listen(listener));
+        Ok(MockHandle {
+            addr,
+            state,
+            task: Some(task),
+        })
+    }
+}
+
+fn app(state: State) -> tide::Server<State> {
+    let mut app = tide::with_state(state);
+    let mut api = app.at(API_PREFIX);
+    api.at("/entries/:lang/:word")
+        .get(|req: Request<State>| async move {
+            Ok(respond(
+                &req,
+                &["entries", req.param("lang")?],
+                req.param("word")?,
+            ))
+        });
+    api.at("/lemmas/:lang/:word")
+        .get(|req: Request<State>| async move {
+            Ok(respond(
+                &req,
+                &["lemmas", req.param("lang")?],
+                req.param("word")?,
+            ))
+        });
+    api.at("/sentences/:lang/:word")
+        .get(|req: Request<State>| async move {
+            Ok(respond(
+                &req,
+                &["sentences", req.param("lang")?],
+                req.param("word")?,
+            ))
+        });
+    api.at("/translations/:source/:target/:word")
+        .get(|req: Request<State>| async move {
+            let dir = ["translations", req.param("source")?, req.param("target")?];
+            Ok(respond(&req, &dir, req.param("word")?))
+        });
+    api.at("/words/:lang")
+        .get(|req: Request<State>| async move {
+            let q = req.url().query_pairs().find(|(name, _)| name == "q");
+            let q = q.map(|(_, q)| q.into_owned()).filter(|q| valid_name(q));
+            Ok(respond_with(&req, &["words", req.param("lang")?], q))
+        });
+    api.at("/grammaticalFeatures/:lang")
+        .get(|req: Request<State>| async move {
+            Ok(respond(&req, &["grammaticalFeatures"], req.param("lang")?))
+        });
+    app
+}
+
+impl MockHandle {
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Base URL to pass to `oxdi::ClientBuilder::base_url`
+    pub fn base_url(&self) -> String {
+        format!("http://{}{}/", self.addr, API_PREFIX)
+    }
+
+    /// Requests received so far, in the order of arrival
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.0.requests.lock().unwrap().clone()
+    }
+
+    /// Stops the server
+    pub async fn stop(mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel().await;
+        }
+    }
+}
+
+impl Drop for MockHandle {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            async_std::task::spawn(task.cancel());
+        }
+    }
+}
+
+/// Response of the fixture named after the percent-encoded path segment `name`
+fn respond(req: &Request<State>, dir: &[&str], name: &str) -> Response {
+    respond_with(req, dir, decode_segment(name))
+}
+
+/// Response of the fixture `name`, `None` if there can't be one
+fn respond_with(req: &Request<State>, dir: &[&str], name: Option<String>) -> Response {
+    let inner = &req.state().0;
+    let request_id = format!(
+        "mock-{}",
+        inner.next_request_id.fetch_add(1, Ordering::Relaxed)
+    );
+    trace!(url = %req.url(), %request_id, "mock request");
+    let path = req.url().path()[API_PREFIX.len()..]
+        .trim_start_matches('/')
+        .to_owned();
+    let failure = take_failure(inner, &path);
+    inner.requests.lock().unwrap().push(MockRequest {
+        path,
+        query: req.url().query().map(str::to_owned),
+        headers: req
+            .iter()
+            .map(|(name, values)| (name.as_str().to_owned(), values.last().as_str().to_owned()))
+            .collect(),
+    });
+    let (status, body) = if !authorized(req, inner) {
+        (StatusCode::Forbidden, error_body("Authentication failed"))
+    } else if let Some(status) = failure {
+        (status, error_body(status.canonical_reason()))
+    } else {
+        match name.and_then(|name| fixture(&inner.fixtures, dir, &name)) {
+            Some(fixture) => fixture,
+            None => (
+                StatusCode::NotFound,
+                error_body(
+                    "No entry found matching supplied source_lang, word and provided filters",
+                ),
+            ),
+        }
+    };
+    let mut resp = Response::builder(status)
+        .header("X-Request-Id", request_id)
+        .content_type(mime::JSON)
+        .body(body);
+    if let (StatusCode::TooManyRequests | StatusCode::ServiceUnavailable, Some(seconds)) =
+        (status, inner.retry_after)
+    {
+        resp = resp.header("Retry-After", seconds.to_string());
+    }
+    resp.build()
+}
+
+/// Status of the next pending [`MockServer::fail`] of the `path`
+fn take_failure(inner: &Inner, path: &str) -> Option<StatusCode> {
+    let mut failures = inner.failures.lock().unwrap();
+    let failure = failures
+        .iter_mut()
+        .find(|failure| failure.path == path && failure.times > 0)?;
+    failure.times -= 1;
+    Some(failure.status)
+}
+
+fn authorized(req: &Request<State>, inner: &Inner) -> bool {
+    let header = |name| req.header(name).map(|values| values.last().as_str());
+    match &inner.credentials {
+        Some((app_id, app_key)) => {
+            header("app_id") == Some(app_id.as_str()) && header("app_key") == Some(app_key.as_str())
+        }
+        None => true,
+    }
+}
+
+fn error_body(error: &str) -> String {
+    serde_json::json!({ "error": error }).to_string()
+}
+
+/// Status and body of the fixture `<dir>/<name>.json` or `<dir>/<name>.<status>.json`,
+/// `dir` segments are percent-encoded
+fn fixture(fixtures: &Path, dir: &[&str], name: &str) -> Option<(StatusCode, String)> {
+    let mut path = fixtures.to_owned();
+    for segment in dir {
+        path.push(decode_segment(segment)?);
+    }
+
+    let ok = path.join(format!("{}.json", name));
+    if let Ok(body) = fs::read_to_string(&ok) {
+        return Some((StatusCode::Ok, body));
+    }
+    let prefix = format!("{}.", name);
+    for entry in fs::read_dir(&path).ok()?.flatten() {
+        let file_name = entry.file_name();
+        let status = file_name
+            .to_str()
+            .and_then(|file_name| file_name.strip_prefix(&prefix))
+            .and_then(|rest| rest.strip_suffix(".json"))
+            .and_then(|status| status.parse::<u16>().ok())
+            .and_then(|status| StatusCode::try_from(status).ok());
+        if let Some(status) = status {
+            match fs::read_to_string(entry.path()) {
+                Ok(body) => return Some((status, body)),
+                Err(err) => warn!(%err, file = ?entry.path(), "failed to read fixture"),
+            }
+        }
+    }
+    None
+}
+
+/// Percent-decoded path segment, `None` if it could escape the fixture directory
+fn decode_segment(segment: &str) -> Option<String> {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = segment.get(i + 1..i + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    let decoded = String::from_utf8(decoded).ok()?;
+    Some(decoded).filter(|decoded| valid_name(decoded))
+}
+
+/// Checks that the fixture `name` can't escape the fixture directory
+fn valid_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && !name.contains(&['/', '\\'][..])
+}
@@ -0,0 +1,48 @@
+use anyhow::{Context, Result};
+use argh::FromArgs;
+use oxdi_mock::{MockServer, API_PREFIX};
+use std::path::PathBuf;
+
+#[derive(Debug, FromArgs)]
+/// Serve the Oxford Dictionaries API from fixture files!
+struct Args {
+    #[argh(option, short = 'a', default = "String::from(\"127.0.0.1:8080\")")]
+    /// address to listen on, by default `127.0.0.1:8080`
+    addr: String,
+
+    #[argh(option, short = 'f', default = "PathBuf::from(\"fixtures\")")]
+    /// fixture directory, by default `fixtures`
+    fixtures: PathBuf,
+
+    #[argh(option)]
+    /// accept only requests with this `app_id`, requires `--app-key`
+    app_id: Option<String>,
+
+    #[argh(option)]
+    /// accept only requests with this `app_key`, requires `--app-id`
+    app_key: Option<String>,
+}
+
+#[async_std::main]
+async fn main() -> Result<()> {
+    let args: Args = argh::from_env();
+    if !args.fixtures.is_dir() {
+        anyhow::bail!("fixture directory {} not found", args.fixtures.display());
+    }
+    let mut server = MockServer::new(&args.fixtures);
+    match (&args.app_id, &args.app_key) {
+        (Some(app_id), Some(app_key)) => server = server.credentials(app_id, app_key),
+        (None, None) => {}
+        _ => anyhow::bail!("--app-id and --app-key must be used together"),
+    }
+    println!(
+        "serving {} at http://{}{}/",
+        args.fixtures.display(),
+        args.addr,
+        API_PREFIX
+    );
+    server
+        .listen(&args.addr)
+        .await
+        .with_context(|| format!("failed to listen on {}", args.addr))
+}
@@ -0,0 +1,370 @@
+//! `oxdi::Client` against the mock server serving `fixtures`
+use oxdi::apis::lemmas::Lemmatized;
+use oxdi::apis::Field;
+use oxdi::models::sentences::Sentence;
+use oxdi::models::{
+    Labels, Lemmatron, LexicalCategoryId, RegionId, RetrieveEntry, RetrieveTranslation,
+    SentencesResults,
+};
+use oxdi::{Client, ClientBuilder, EntriesQuery, Error, Language, RateLimiter, RetryPolicy};
+use oxdi_mock::{MockHandle, MockServer};
+use std::time::{Duration, Instant};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+
+async fn start() -> (MockHandle, Client) {
+    let mock = MockServer::new(FIXTURES)
+        .credentials("id", "key")
+        .spawn()
+        .await
+        .unwrap();
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .base_url(&mock.base_url())
+        .build()
+        .unwrap();
+    (mock, client)
+}
+
+#[async_std::test]
+async fn entries() {
+    let (_mock, client) = start().await;
+    let entry: RetrieveEntry = client.entries("crab", &EntriesQuery::new()).await.unwrap();
+    let headword = &entry.results.unwrap()[0];
+    assert_eq!(headword.id, "crab");
+    assert_eq!(headword.lexical_entries.len(), 2);
+}
+
+#[async_std::test]
+async fn lemmas() {
+    let (_mock, client) = start().await;
+    let lemmatron: Lemmatron = client.lemmas("crabs", &EntriesQuery::new()).await.unwrap();
+    assert_eq!(lemmatron.lemmas(), ["crab"]);
+}
+
+#[async_std::test]
+async fn words() {
+    let (mock, client) = start().await;
+    let query = EntriesQuery::new()
+        .lexical_category(LexicalCategoryId::Noun)
+        .fields(&[Field::Definitions]);
+    let entry: RetrieveEntry = client.words("ice cream", &query).await.unwrap();
+    assert_eq!(entry.results.unwrap()[0].id, "ice_cream");
+
+    let requests = mock.requests();
+    assert_eq!(requests[0].path, "words/en-gb");
+    assert_eq!(
+        requests[0].query.as_deref(),
+        Some("q=ice%20cream&fields=definitions&lexicalCategory=noun")
+    );
+
+    let err = client
+        .words::<RetrieveEntry>("o'clock & co", &EntriesQuery::new())
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+    // the `&` of the word doesn't start another param
+    assert_eq!(
+        mock.requests()[1].query.as_deref(),
+        Some("q=o%27clock%20%26%20co")
+    );
+}
+
+#[async_std::test]
+async fn lemmatized_entries() {
+    let (_mock, client) = start().await;
+    let query = EntriesQuery::new();
+    let swimming: Lemmatized<RetrieveEntry> =
+        client.entries_lemmatized("swimming", &query).await.unwrap();
+    // the lemma equal to the word itself is skipped
+    assert_eq!(swimming.lemma.as_deref(), Some("swim"));
+    assert_eq!(swimming.value.results.unwrap()[0].id, "swim");
+
+    // found directly
+    let crab: Lemmatized<RetrieveEntry> = client.entries_lemmatized("crab", &query).await.unwrap();
+    assert_eq!(crab.lemma, None);
+
+    // none of the lemmas has entries, or there are no lemmas
+    for word in ["xyzzies", "lobster"] {
+        let err = client
+            .entries_lemmatized::<RetrieveEntry>(word, &query)
+            .await
+            .unwrap_err();
+        assert!(err.is_not_found());
+        assert_eq!(
+            err.details().unwrap().path,
+            format!("entries/en-gb/{}", word)
+        );
+    }
+}
+
+#[async_std::test]
+async fn translations() {
+    let (_mock, client) = start().await;
+    let translation: RetrieveTranslation = client
+        .translations("crab", Language::Es, &EntriesQuery::new())
+        .await
+        .unwrap();
+    let results = translation.results.unwrap();
+    let sense = &results[0].lexical_entries[0].entries.as_ref().unwrap()[0]
+        .senses
+        .as_ref()
+        .unwrap()[0];
+    assert_eq!(sense.translations.as_ref().unwrap()[0].text, "cangrejo");
+}
+
+#[async_std::test]
+async fn sentences() {
+    let (_mock, client) = start().await;
+    let results: SentencesResults = client
+        .sentences("crab", &EntriesQuery::new())
+        .await
+        .unwrap();
+    assert_eq!(results.sentences().count(), 4);
+
+    // the sentence without senses is in no group
+    let by_sense = results.by_sense();
+    let texts = |sentences: &[&Sentence]| -> Vec<String> {
+        sentences
+            .iter()
+            .map(|sentence| sentence.text.clone())
+            .collect()
+    };
+    assert_eq!(by_sense.len(), 3);
+    assert_eq!(
+        texts(&by_sense["m_en_gbus0236170.005"]),
+        [
+            "The crab scuttled away across the sand.",
+            "We had dressed crab for lunch."
+        ]
+    );
+    assert_eq!(
+        texts(&by_sense["m_en_gbus0236170.009"]),
+        ["We had dressed crab for lunch."]
+    );
+    assert_eq!(
+        texts(&by_sense["m_en_gbus0236170.012"]),
+        ["He caught a crab and nearly lost his oar."]
+    );
+
+    // the sentence without regions is in no group
+    let by_region = results.by_region();
+    assert_eq!(by_region.len(), 2);
+    assert_eq!(
+        texts(&by_region[&RegionId::British]),
+        [
+            "The crab scuttled away across the sand.",
+            "We had dressed crab for lunch."
+        ]
+    );
+    assert_eq!(
+        texts(&by_region[&RegionId::NorthAmerican]),
+        [
+            "We had dressed crab for lunch.",
+            "Crab season opens next week."
+        ]
+    );
+    // but every sentence has a sense or a region
+    let grouped = by_sense.values().chain(by_region.values()).flatten();
+    let mut grouped: Vec<&str> = grouped.map(|sentence| sentence.text.as_str()).collect();
+    grouped.sort_unstable();
+    grouped.dedup();
+    assert_eq!(grouped.len(), 4);
+}
+
+#[async_std::test]
+async fn grammatical_features() {
+    let (_mock, client) = start().await;
+    let labels: Labels = client.grammatical_features().await.unwrap();
+    assert_eq!(labels.text("plural"), Some("Plural"));
+    assert_eq!(labels.types["past"], "Tense");
+}
+
+#[async_std::test]
+async fn not_found() {
+    let (_mock, client) = start().await;
+    let err = client
+        .entries::<RetrieveEntry>("lobster", &EntriesQuery::new())
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+    let details = err.details().unwrap();
+    assert!(details.x_request_id.as_ref().unwrap().starts_with("mock-"));
+    assert!(details.explanation.is_some());
+}
+
+#[async_std::test]
+async fn error_fixture() {
+    let (_mock, client) = start().await;
+    let err = client
+        .entries::<RetrieveEntry>("teapot", &EntriesQuery::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::ServerError { status, .. } if u16::from(status) == 503));
+    assert_eq!(
+        err.details().unwrap().explanation.as_ref().unwrap().error,
+        "Service temporarily unavailable"
+    );
+}
+
+#[async_std::test]
+async fn wrong_credentials() {
+    let (mock, _) = start().await;
+    let client = ClientBuilder::new("id".to_owned(), "wrong".to_owned(), Language::EnGb)
+        .base_url(&mock.base_url())
+        .build()
+        .unwrap();
+    let err = client
+        .entries::<RetrieveEntry>("crab", &EntriesQuery::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::Forbidden(_)));
+}
+
+#[async_std::test]
+async fn custom_headers() {
+    let (mock, _) = start().await;
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .base_url(&mock.base_url())
+        .user_agent("oxdi-tests/1.0")
+        .header("X-Trace", "abc")
+        .build()
+        .unwrap();
+    let _: RetrieveEntry = client.entries("crab", &EntriesQuery::new()).await.unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].header("user-agent"), Some("oxdi-tests/1.0"));
+    assert_eq!(requests[0].header("x-trace"), Some("abc"));
+    assert_eq!(requests[0].header("app_id"), Some("id"));
+}
+
+fn retrying_client(mock: &MockHandle, limiter: RateLimiter) -> Client {
+    let policy = RetryPolicy::new()
+        .max_attempts(3)
+        .initial_backoff(Duration::from_millis(10))
+        .jitter(false);
+    ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .base_url(&mock.base_url())
+        .retry_policy(policy)
+        .rate_limiter(limiter)
+        .build()
+        .unwrap()
+}
+
+#[async_std::test]
+async fn retries_exhausted() {
+    let (mock, _) = start().await;
+    let limiter = RateLimiter::new().per_month(100);
+    let client = retrying_client(&mock, limiter.clone());
+    let err = client
+        .entries::<RetrieveEntry>("teapot", &EntriesQuery::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::ServerError { status, .. } if u16::from(status) == 503));
+    assert_eq!(
+        err.details().unwrap().x_request_id.as_deref(),
+        Some("mock-3")
+    );
+    assert_eq!(mock.requests().len(), 3);
+    // every attempt takes from the budget
+    assert_eq!(limiter.remaining().per_month, Some(97));
+}
+
+#[async_std::test]
+async fn retry_succeeds() {
+    let mock = MockServer::new(FIXTURES)
+        .fail("entries/en-gb/crab", 503, 1)
+        .spawn()
+        .await
+        .unwrap();
+    let client = retrying_client(&mock, RateLimiter::new());
+    let entry: RetrieveEntry = client.entries("crab", &EntriesQuery::new()).await.unwrap();
+    assert_eq!(entry.results.unwrap()[0].id, "crab");
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[async_std::test]
+async fn retry_after() {
+    let mock = MockServer::new(FIXTURES)
+        .fail("entries/en-gb/crab", 429, 1)
+        .retry_after(1)
+        .spawn()
+        .await
+        .unwrap();
+    let client = retrying_client(&mock, RateLimiter::new());
+    let start = Instant::now();
+    let _: RetrieveEntry = client.entries("crab", &EntriesQuery::new()).await.unwrap();
+    // waits for `Retry-After` instead of the 10ms backoff
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    assert_eq!(mock.requests().len(), 2);
+}
+
+async fn failing(status: u16) -> Error {
+    let mock = MockServer::new(FIXTURES)
+        .fail("entries/en-gb/crab", status, 1)
+        .retry_after(2)
+        .spawn()
+        .await
+        .unwrap();
+    let client = ClientBuilder::new("id".to_owned(), "key".to_owned(), Language::EnGb)
+        .base_url(&mock.base_url())
+        .build()
+        .unwrap();
+    client
+        .entries::<RetrieveEntry>("crab", &EntriesQuery::new())
+        .await
+        .unwrap_err()
+}
+
+#[async_std::test]
+async fn api_errors() {
+    assert!(matches!(failing(401).await, Error::Unauthorized(_)));
+    assert!(matches!(failing(414).await, Error::TooLong(_)));
+    let err = failing(429).await;
+    assert!(matches!(
+        err,
+        Error::RateLimited {
+            retry_after: Some(retry_after),
+            ..
+        } if retry_after == Duration::from_secs(2)
+    ));
+    let details = err.details().unwrap();
+    assert_eq!(details.path, "entries/en-gb/crab");
+    assert_eq!(details.x_request_id.as_deref(), Some("mock-1"));
+    assert_eq!(
+        details.explanation.as_ref().unwrap().error,
+        "Too Many Requests"
+    );
+    // not mapped to a variant
+    let err = failing(418).await;
+    assert!(matches!(err, Error::HttpStatus { status, .. } if u16::from(status) == 418));
+}
+
+#[async_std::test]
+async fn decode_error() {
+    let (_mock, client) = start().await;
+    let err = client
+        .entries::<RetrieveEntry>("garbled", &EntriesQuery::new())
+        .await
+        .unwrap_err();
+    match err {
+        Error::Decode {
+            path,
+            json_path,
+            body,
+            ..
+        } => {
+            assert_eq!(path, "entries/en-gb/garbled");
+            assert_eq!(
+                json_path,
+                "results[0].lexicalEntries[0].entries[0].senses[0].id"
+            );
+            assert_eq!(body.chars().count(), 256);
+            let fixture =
+                std::fs::read_to_string(format!("{}/entries/en-gb/garbled.json", FIXTURES))
+                    .unwrap();
+            assert!(fixture.starts_with(&body));
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+}